@@ -1 +1 @@
-pub mod stateful;
+pub mod stateful_contract;
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
-use solid::Encode;
+use solid::{Decode, Encode};
 
-#[derive(Encode)]
+#[derive(Decode)]
 pub struct GetDetailsNamedOutput<'a> {
     pub message_: &'a str,
     pub random_bytes_: solid::Bytes<'a>,
@@ -11,6 +11,7 @@ pub struct GetDetailsNamedOutput<'a> {
 pub struct StatefulContract;
 
 impl StatefulContract {
+
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn new(message_: &str, random_bytes_: solid::Bytes<'_>, random_bytes10_: solid::bytesfix::BytesFix<'_, 10>) -> Vec<u8> {
@@ -21,53 +22,80 @@ impl StatefulContract {
             .build()
     }
 
+    /// `getDetails()`
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn get_details() -> Vec<u8> {
         solid::Builder::new()
-            .name("getDetails")
+            .selector(solid::selector!("getDetails()"))
             .build()
     }
 
+    /// Values returned by `getDetails()`
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_get_details_output<'a>(buf: &'a [u8]) -> solid::Result<(&'a str, solid::Bytes<'a>, solid::bytesfix::BytesFix<'a, 10>)> {
+        solid::decode::Decode::decode(buf)
+    }
+
+    /// `getDetailsNamed()`
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn get_details_named() -> Vec<u8> {
         solid::Builder::new()
-            .name("getDetailsNamed")
+            .selector(solid::selector!("getDetailsNamed()"))
             .build()
     }
 
+    /// Values returned by `getDetailsNamed()`
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_get_details_named_output<'a>(buf: &'a [u8]) -> solid::Result<GetDetailsNamedOutput<'a>> {
+        solid::decode::Decode::decode(buf)
+    }
+
+    /// `getMessage()`
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn get_message() -> Vec<u8> {
         solid::Builder::new()
-            .name("getMessage")
+            .selector(solid::selector!("getMessage()"))
             .build()
     }
 
+    /// Values returned by `getMessage()`
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_get_message_output<'a>(buf: &'a [u8]) -> solid::Result<&'a str> {
+        solid::decode::decode_at(buf, 0)
+    }
+
+    /// `kill()`
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn kill() -> Vec<u8> {
         solid::Builder::new()
-            .name("kill")
+            .selector(solid::selector!("kill()"))
             .build()
     }
 
+    /// `printMessage(string[])`
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn print_message(messages_: Vec<&str>) -> Vec<u8> {
         solid::Builder::new()
             .push(messages_)
-            .name("printMessage")
+            .selector(solid::selector!("printMessage(string[])"))
             .build()
     }
 
+    /// `setMessage(string)`
     #[rustfmt::skip]
     #[allow(dead_code)]
     pub fn set_message(message_: &str) -> Vec<u8> {
         solid::Builder::new()
             .push(message_)
-            .name("setMessage")
+            .selector(solid::selector!("setMessage(string)"))
             .build()
     }
 }
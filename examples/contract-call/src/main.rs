@@ -48,9 +48,9 @@ pub struct ContractCallSerde<'a> {
 // Use the `#[solid(constructor)]` attribute to declare a struct as a constructor.
 // This is important because constructors do not have the function name prefix,
 // unlike all other functions. Usually the struct name is used as the function
-// name. To rename the function use the `#[solid(name = "<function_name>")]`
+// name. To rename the function use the `#[solid(rename = "<function_name>")]`
 // where `<function_name>` is the name of your function.
-// ie. `#[solid(name = "transfer")]`.
+// ie. `#[solid(rename = "transfer")]`.
 #[derive(Encode)]
 struct ContractConstructorEncode<'a> {
     pub value: u128,
@@ -63,7 +63,6 @@ struct ContractConstructorEncode<'a> {
 // wrappers around `[u8; 32]`. The point of them is to support all
 // `int`/`uint` Solidity types.
 #[derive(Decode)]
-#[solid(error)]
 struct ContractCallResponse<'a> {
     int: Uint256,
     // Note: &'a [u8] is *not* the same as `Bytes<'a>`. The former is is `uint8[]` in solidity
@@ -0,0 +1,82 @@
+use std::{
+    fmt,
+    path::PathBuf,
+};
+
+/// Simple wrapper around `std::result::Result`
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Errors returned by `Generator::generate`
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// `Generator::out_dir` was not called and `OUT_DIR` is not set
+    MissingOutDir,
+    /// Reading an ABI or writing a generated file failed
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// The file is not a JSON ABI, solc output or artifact
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The ABI of `contract` in the file could not be parsed
+    Abi {
+        path: PathBuf,
+        contract: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// A parameter of `contract` has a type outside of the ABI type grammar
+    UnsupportedType {
+        path: PathBuf,
+        contract: String,
+        ty: String,
+    },
+    /// Several contracts of the same name are declared in sources of the same name, so they
+    /// would all be written to `path`
+    DuplicateFile { path: PathBuf },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingOutDir => {
+                formatter.write_str("no output directory given and `OUT_DIR` is not set")
+            }
+            Error::Io { path, source } => write!(formatter, "{}: {}", path.display(), source),
+            Error::Json { path, source } => write!(
+                formatter,
+                "{}: invalid contract JSON: {}",
+                path.display(),
+                source
+            ),
+            Error::Abi {
+                path,
+                contract,
+                source,
+            } => write!(
+                formatter,
+                "{}: invalid ABI of `{}`: {}",
+                path.display(),
+                contract,
+                source
+            ),
+            Error::UnsupportedType { path, contract, ty } => write!(
+                formatter,
+                "{}: unsupported type `{}` in the ABI of `{}`",
+                path.display(),
+                ty,
+                contract
+            ),
+            Error::DuplicateFile { path } => write!(
+                formatter,
+                "{}: would be generated for several contracts of the same name",
+                path.display()
+            ),
+        }
+    }
+}
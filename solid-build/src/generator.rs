@@ -0,0 +1,882 @@
+use super::{
+    Error,
+    Numeric,
+    Result,
+    SolidityContract,
+    SolidityField,
+    TypeOptions,
+};
+use heck::SnakeCase;
+use serde::Deserialize;
+use serde_json::{
+    Map,
+    Value,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    env,
+    fs,
+    io::Write,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+struct SolidityAbi {
+    contracts: Map<String, Value>,
+}
+
+/// Contract in either `--combined-json` or `--standard-json` output
+#[derive(Debug, Deserialize)]
+struct SolidityAbiContract {
+    /// A JSON string in `--combined-json abi` output, an array otherwise
+    abi: Value,
+    #[serde(default)]
+    evm: Evm,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Evm {
+    bytecode: Option<Bytecode>,
+    deployed_bytecode: Option<Bytecode>,
+    #[serde(default)]
+    method_identifiers: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Bytecode {
+    object: String,
+}
+
+/// Foundry, Hardhat or Truffle artifact of a single contract
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Artifact {
+    abi: Value,
+    /// Set by Hardhat and Truffle, Foundry only records it in the metadata
+    contract_name: Option<String>,
+    bytecode: Option<ArtifactBytecode>,
+    deployed_bytecode: Option<ArtifactBytecode>,
+    #[serde(default)]
+    method_identifiers: BTreeMap<String, String>,
+    /// An object in Foundry artifacts and a JSON string in Truffle ones
+    metadata: Option<Value>,
+}
+
+/// Hardhat and Truffle store the bytecode itself, Foundry the object of solc's output
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ArtifactBytecode {
+    Hex(String),
+    Object(Bytecode),
+}
+
+impl From<ArtifactBytecode> for Bytecode {
+    fn from(bytecode: ArtifactBytecode) -> Self {
+        match bytecode {
+            ArtifactBytecode::Hex(object) => Bytecode { object },
+            ArtifactBytecode::Object(bytecode) => bytecode,
+        }
+    }
+}
+
+impl Artifact {
+    /// `contractName`, the compilation target in Foundry's metadata or the file name
+    fn name(&self, path: &Path) -> String {
+        let target = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.pointer("/settings/compilationTarget"))
+            .and_then(Value::as_object)
+            .and_then(|target| target.values().next())
+            .and_then(Value::as_str);
+
+        self.contract_name
+            .as_deref()
+            .or(target)
+            .or_else(|| path.file_stem().and_then(|stem| stem.to_str()))
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+/// Generates Rust bindings from the output of `solc --combined-json abi` or `solc --standard-json`
+/// and from Foundry, Hardhat or Truffle artifacts
+///
+/// Meant to be called from a `build.rs` script, in which case the generated files are
+/// written to `OUT_DIR` and cargo is told to rerun the script when an ABI changes.
+#[derive(Debug)]
+pub struct Generator {
+    abis: Vec<PathBuf>,
+    out_dir: Option<PathBuf>,
+    types: TypeOptions,
+    mod_file: bool,
+    rerun_if_changed: bool,
+    renames: BTreeMap<String, String>,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Generator {
+            abis: Vec::new(),
+            out_dir: None,
+            types: TypeOptions::default(),
+            mod_file: false,
+            rerun_if_changed: true,
+            renames: BTreeMap::new(),
+        }
+    }
+}
+
+impl Generator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `solc --combined-json abi` or `solc --standard-json` output file, a build artifact
+    /// or a directory of artifacts to generate bindings for
+    pub fn abi<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.abis.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Directory the generated files are written to, defaults to `OUT_DIR`
+    pub fn out_dir<P: AsRef<Path>>(mut self, out_dir: P) -> Self {
+        self.out_dir = Some(out_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Use `BytesFix<N>` and `Int<N, M>` instead of the stable types
+    pub fn nightly(mut self, nightly: bool) -> Self {
+        self.types.nightly = nightly;
+        self
+    }
+
+    /// Rust types of the integers without a primitive of their width, `Numeric::Solid` by
+    /// default
+    pub fn numeric(mut self, numeric: Numeric) -> Self {
+        self.types.numeric = numeric;
+        self
+    }
+
+    /// Also write a `mod.rs` declaring every generated file as a module
+    pub fn mod_file(mut self, mod_file: bool) -> Self {
+        self.mod_file = mod_file;
+        self
+    }
+
+    /// Print `cargo:rerun-if-changed` for every ABI, enabled by default
+    pub fn rerun_if_changed(mut self, rerun_if_changed: bool) -> Self {
+        self.rerun_if_changed = rerun_if_changed;
+        self
+    }
+
+    /// Generates the function, event or error with the canonical `signature`, such as
+    /// `safeTransferFrom(address,address,uint256,bytes)`, under `name` rather than its
+    /// Solidity name
+    ///
+    /// `name` is cased like a Solidity name, `safeTransferFromWithData` is generated as
+    /// `safe_transfer_from_with_data` for a function and kept as is for an event or error.
+    pub fn rename<S: Into<String>, N: Into<String>>(mut self, signature: S, name: N) -> Self {
+        self.renames.insert(signature.into(), name.into());
+        self
+    }
+
+    /// Writes one file per contract and returns their paths
+    ///
+    /// Each contract is written to a file named after it in snake case, so several contracts
+    /// declared in the same Solidity source get a file each. Contracts of the same name declared
+    /// in different sources are written to files prefixed with the source's name, such as
+    /// `mocks_token.rs` for `Token` in `Mocks.sol`.
+    pub fn generate(&self) -> Result<Vec<PathBuf>> {
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.clone(),
+            None => env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(Error::MissingOutDir)?,
+        };
+
+        fs::create_dir_all(&out_dir).map_err(io(&out_dir))?;
+
+        let mut mod_file = if self.mod_file {
+            let path = out_dir.join("mod.rs");
+            Some((fs::File::create(&path).map_err(io(&path))?, path))
+        } else {
+            None
+        };
+
+        let mut generated = Vec::new();
+        for (filename, source) in self.files(&out_dir)? {
+            fs::write(&filename, source).map_err(io(&filename))?;
+
+            if let (Some((file, path)), Some(Some(name))) = (
+                &mut mod_file,
+                filename.file_stem().map(|name| name.to_str()),
+            ) {
+                writeln!(file, "pub mod {};", name).map_err(io(path))?;
+            }
+
+            generated.push(filename);
+        }
+
+        Ok(generated)
+    }
+
+    /// Generates the bindings without writing them, returning the name of each contract's file
+    /// without its extension along with its source
+    ///
+    /// `contract!` places each source in a module of that name, like the `mod.rs` written by
+    /// `generate`.
+    pub fn sources(&self) -> Result<Vec<(String, String)>> {
+        Ok(self
+            .files(Path::new(""))?
+            .into_iter()
+            .map(|(filename, source)| {
+                let name = filename
+                    .file_stem()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default()
+                    .to_string();
+                (name, source)
+            })
+            .collect())
+    }
+
+    /// Path below `out_dir` and source of the file of every contract
+    ///
+    /// Contracts of the same name declared in different sources would overwrite each other's
+    /// file, so their files are prefixed with the name of their source instead.
+    fn files(&self, out_dir: &Path) -> Result<Vec<(PathBuf, String)>> {
+        let mut contracts = Vec::new();
+        for path in &self.abis {
+            if self.rerun_if_changed {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+
+            for file in abi_files(path)? {
+                contracts.extend(self.contracts(&file, out_dir)?);
+            }
+        }
+
+        let mut counts = BTreeMap::new();
+        for (_, contract) in &contracts {
+            *counts.entry(contract.filename.clone()).or_insert(0) += 1;
+        }
+
+        let mut filenames = BTreeSet::new();
+        let mut files = Vec::new();
+        for (source, mut contract) in contracts {
+            if counts[&contract.filename] > 1 {
+                contract.filename = out_dir.join(format!(
+                    "{}_{}.rs",
+                    source.to_snake_case(),
+                    contract.contract.to_snake_case()
+                ));
+            }
+
+            if !filenames.insert(contract.filename.clone()) {
+                return Err(Error::DuplicateFile {
+                    path: contract.filename,
+                });
+            }
+
+            contract.disambiguate(&self.renames);
+            contract.name_structs();
+
+            let source = format!(
+                "{}{}",
+                contract.types(self.types),
+                contract.functions(self.types)
+            );
+
+            files.push((contract.filename, source));
+        }
+
+        Ok(files)
+    }
+
+    /// Every contract in the file, along with the name of the source that declares it
+    ///
+    /// Solc output records the Solidity source of each contract, while artifacts and plain ABIs
+    /// are named after the directory they are in, which Foundry and Hardhat name after the
+    /// source.
+    fn contracts(&self, path: &Path, out_dir: &Path) -> Result<Vec<(String, SolidityContract)>> {
+        let directory = path.parent().map(stem).unwrap_or_default();
+        let contents = fs::read_to_string(path).map_err(io(path))?;
+
+        let json = |source| Error::Json {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let value: Value = serde_json::from_str(&contents).map_err(json)?;
+        if value.is_array() {
+            // A plain JSON ABI, whose contract is named after the file
+            let name = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default();
+
+            return Ok(vec![(
+                directory,
+                contract(
+                    path,
+                    out_dir,
+                    &name.to_snake_case(),
+                    name,
+                    value,
+                    Evm::default(),
+                )?,
+            )]);
+        }
+
+        if value.get("abi").is_some() {
+            let artifact: Artifact = serde_json::from_value(value).map_err(json)?;
+            let name = artifact.name(path);
+            let evm = Evm {
+                bytecode: artifact.bytecode.map(Bytecode::from),
+                deployed_bytecode: artifact.deployed_bytecode.map(Bytecode::from),
+                method_identifiers: artifact.method_identifiers,
+            };
+
+            let filename = name.to_snake_case();
+            return Ok(vec![(
+                directory,
+                contract(path, out_dir, &filename, &name, artifact.abi, evm)?,
+            )]);
+        }
+
+        let abi: SolidityAbi = serde_json::from_value(value).map_err(json)?;
+
+        let mut contracts = Vec::new();
+        for (key, value) in abi.contracts {
+            match key.rfind(':') {
+                // `--combined-json` keys contracts by `file.sol:Contract`
+                Some(colon) => contracts.push((
+                    stem(Path::new(&key[..colon])),
+                    solc_contract(path, out_dir, &key[colon + 1..], value)?,
+                )),
+                // `--standard-json` keys them by source file and then by name
+                None => {
+                    let sources: Map<String, Value> =
+                        serde_json::from_value(value).map_err(json)?;
+
+                    for (name, value) in sources {
+                        contracts.push((
+                            stem(Path::new(&key)),
+                            solc_contract(path, out_dir, &name, value)?,
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(contracts)
+    }
+}
+
+/// Contract of solc output, written to a file named after the contract like artifacts are
+fn solc_contract(
+    path: &Path,
+    out_dir: &Path,
+    name: &str,
+    value: Value,
+) -> Result<SolidityContract> {
+    let value: SolidityAbiContract =
+        serde_json::from_value(value).map_err(|source| Error::Abi {
+            path: path.to_path_buf(),
+            contract: name.to_string(),
+            source,
+        })?;
+
+    contract(
+        path,
+        out_dir,
+        &name.to_snake_case(),
+        name,
+        value.abi,
+        value.evm,
+    )
+}
+
+/// Parses the ABI of the contract `name`, which is written to `{filename}.rs`
+fn contract(
+    path: &Path,
+    out_dir: &Path,
+    filename: &str,
+    name: &str,
+    abi: Value,
+    evm: Evm,
+) -> Result<SolidityContract> {
+    let mut fields: Vec<SolidityField> = match abi {
+        Value::String(abi) => serde_json::from_str(&abi),
+        abi => serde_json::from_value(abi),
+    }
+    .map_err(|source| Error::Abi {
+        path: path.to_path_buf(),
+        contract: name.to_string(),
+        source,
+    })?;
+
+    for field in &mut fields {
+        if let Some(ty) = field.unsupported_type() {
+            return Err(Error::UnsupportedType {
+                path: path.to_path_buf(),
+                contract: name.to_string(),
+                ty: ty.to_string(),
+            });
+        }
+
+        field.infer_state_mutability();
+    }
+
+    // Artifacts prefix their bytecode with `0x`, solc output does not
+    let hex = |bytecode: Bytecode| bytecode.object.trim_start_matches("0x").to_string();
+
+    Ok(SolidityContract {
+        filename: out_dir.join(format!("{}.rs", filename)),
+        contract: name.to_string(),
+        fields,
+        bytecode: evm.bytecode.map(hex),
+        deployed_bytecode: evm.deployed_bytecode.map(hex),
+        method_identifiers: evm.method_identifiers,
+    })
+}
+
+/// The file itself, or every artifact below a directory in a stable order
+///
+/// Hardhat's `.dbg.json` files and the `build-info` directories of Hardhat and Foundry hold
+/// compiler input and debug information rather than ABIs, so they are skipped.
+fn abi_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut entries = fs::read_dir(path)
+        .map_err(io(path))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(io(path))?;
+    entries.sort();
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let name = entry
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if entry.is_dir() {
+            if name != "build-info" {
+                files.extend(abi_files(&entry)?);
+            }
+        } else if name.ends_with(".json") && !name.ends_with(".dbg.json") {
+            files.push(entry);
+        }
+    }
+
+    Ok(files)
+}
+
+/// File name of `path` without its extension
+fn stem(path: &Path) -> String {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn io(path: &Path) -> impl Fn(std::io::Error) -> Error + '_ {
+    move |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-generate");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../examples/cargo-solid-example/stateful.json");
+
+        let generated = Generator::new()
+            .abi(abi)
+            .out_dir(&out_dir)
+            .mod_file(true)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        assert_eq!(generated, vec![out_dir.join("stateful_contract.rs")]);
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains("pub struct StatefulContract;"));
+        assert!(source.contains("pub fn get_details()"));
+        assert!(source.contains("#[derive(Decode)]\npub struct GetDetailsNamedOutput<'a>"));
+        assert!(source.contains(
+            "pub fn decode_get_details_output<'a>(buf: &'a [u8]) -> solid::Result<(&'a str, \
+             solid::Bytes<'a>, solid::bytesfix::Bytes10)>"
+        ));
+        assert!(source.contains(
+            "pub fn decode_get_message_output<'a>(buf: &'a [u8]) -> solid::Result<&'a str>"
+        ));
+        assert!(!source.contains("decode_kill_output"));
+
+        let mod_file = fs::read_to_string(out_dir.join("mod.rs")).unwrap();
+        assert_eq!(mod_file, "pub mod stateful_contract;\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn structs() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-structs");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/vault.json");
+
+        let generated = Generator::new()
+            .abi(abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert_eq!(source.matches("pub struct Position<'a>").count(), 1);
+        assert!(source.contains("pub mod vault {"));
+        assert!(source.contains("pub struct Fee<'a> {\n    pub rate: u16,"));
+        assert!(source.contains("pub struct LegacyOrder<'a>"));
+        assert!(source.contains("pub fn open(position: vault::Position<'_>)"));
+        assert!(source.contains("solid::selector!(\"open((string,uint256[],(uint16,string)))\")"));
+        assert!(source.contains("solid::Result<Vec<vault::Position<'a>>>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn events_and_errors() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-events");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/vault.json");
+
+        let generated = Generator::new()
+            .abi(abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains("pub struct Labeled<'a> {\n    pub id: u64,\n    pub label: solid::bytesfix::Bytes32,\n    pub memo: &'a str,\n}"));
+        assert!(source.contains("pub fn decode_log(topics: &'a [[u8; 32]], data: &'a [u8])"));
+        assert!(source.contains("amount: solid::decode::decode_at(&topics.first()"));
+        assert!(source.contains("pub struct Closed {}"));
+        assert!(source.contains("pub const SELECTOR: [u8; 4] = [0x1c, 0xdd, 0xe6, 0x7b];"));
+        assert!(source.contains(
+            "pub enum VaultEvents<'a> {\n    Opened(Opened<'a>),\n    Labeled(Labeled<'a>),\n}"
+        ));
+        assert!(source.contains("pub enum VaultErrors<'a> {\n    Closed(Closed),\n    Insufficient(Insufficient),\n    Rejected(Rejected<'a>),\n}"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn numeric() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-numeric");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/vault.json");
+
+        let generated = Generator::new()
+            .abi(&abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains(
+            "pub fn quote(owner: solid::Address, exact: bool, ticks: [solid::int::Uint24; 3])"
+        ));
+        assert!(source.contains("solid::Result<solid::int::Int24>"));
+
+        let generated = Generator::new()
+            .abi(&abi)
+            .out_dir(&out_dir)
+            .numeric(Numeric::Primitive)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains("ticks: [u32; 3]"));
+        assert!(source.contains("solid::Result<i32>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let out_dir = env::temp_dir().join("solid-build-unsupported");
+        let abi = out_dir.join("unsupported.json");
+        fs::create_dir_all(&out_dir).unwrap();
+        fs::write(
+            &abi,
+            r#"{"contracts": {"a.sol:A": {"abi": "[{\"type\": \"function\", \"name\": \"f\", \"inputs\": [{\"name\": \"x\", \"type\": \"uint7\"}]}]"}}}"#,
+        )
+        .unwrap();
+
+        let err = Generator::new()
+            .abi(&abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()
+            .unwrap_err();
+
+        assert!(matches!(err, Error::UnsupportedType { ref ty, .. } if ty == "uint7"));
+    }
+
+    #[test]
+    fn standard_json() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-standard");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/standard.json");
+
+        let generated = Generator::new()
+            .abi(abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        assert_eq!(generated, vec![out_dir.join("token.rs")]);
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains("pub fn transfer(to: solid::Address, amount: solid::int::Uint256)"));
+        assert!(source.contains("pub const BYTECODE: &str = \"6080604052348015"));
+        assert!(source.contains("pub const DEPLOYED_BYTECODE: &str = \"6080604052600080fd"));
+        assert!(source.contains("(\"transfer(address,uint256)\", [0xa9, 0x05, 0x9c, 0xbb]),"));
+        assert!(source.contains("pub struct Transfer {"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn plain_abi() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-plain-abi");
+        let abi = out_dir.join("Pausable.json");
+        fs::create_dir_all(&out_dir).unwrap();
+        fs::write(
+            &abi,
+            r#"[{"type": "function", "name": "pause", "inputs": [], "outputs": [], "stateMutability": "nonpayable"}]"#,
+        )
+        .unwrap();
+
+        let sources = Generator::new()
+            .abi(&abi)
+            .rerun_if_changed(false)
+            .sources()?;
+
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].0, "pausable");
+        assert!(sources[0].1.contains("pub struct Pausable;"));
+        assert!(sources[0].1.contains("pub fn pause()"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn contracts_of_one_source() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-one-source");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/two_contracts.json");
+
+        let generated = Generator::new()
+            .abi(abi)
+            .out_dir(&out_dir)
+            .mod_file(true)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        assert_eq!(
+            generated,
+            vec![out_dir.join("token.rs"), out_dir.join("token_factory.rs")]
+        );
+
+        let token = fs::read_to_string(&generated[0]).unwrap();
+        assert!(token.contains("pub struct Token;"));
+        assert!(token.contains("pub fn transfer("));
+
+        let factory = fs::read_to_string(&generated[1]).unwrap();
+        assert!(factory.contains("pub struct TokenFactory;"));
+        assert!(factory.contains("pub fn create(symbol: &str)"));
+
+        let mod_file = fs::read_to_string(out_dir.join("mod.rs")).unwrap();
+        assert_eq!(mod_file, "pub mod token;\npub mod token_factory;\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn contracts_of_the_same_name() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-same-name");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/same_name.json");
+
+        let generated = Generator::new()
+            .abi(&abi)
+            .out_dir(&out_dir)
+            .mod_file(true)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        assert_eq!(
+            generated,
+            vec![
+                out_dir.join("token_token.rs"),
+                out_dir.join("mocks_token.rs")
+            ]
+        );
+
+        let token = fs::read_to_string(&generated[0]).unwrap();
+        assert!(token.contains("pub struct Token;"));
+        assert!(token.contains("pub fn transfer("));
+
+        let mock = fs::read_to_string(&generated[1]).unwrap();
+        assert!(mock.contains("pub struct Token;"));
+        assert!(mock.contains("pub fn mint("));
+
+        let mod_file = fs::read_to_string(out_dir.join("mod.rs")).unwrap();
+        assert_eq!(mod_file, "pub mod token_token;\npub mod mocks_token;\n");
+
+        // The same sources given twice cannot be told apart
+        let result = Generator::new()
+            .abi(&abi)
+            .abi(&abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate();
+        assert!(matches!(result, Err(Error::DuplicateFile { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn abi_array() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-abi-array");
+        let abi = out_dir.join("array.json");
+        fs::create_dir_all(&out_dir).unwrap();
+        fs::write(
+            &abi,
+            r#"{"contracts": {"a.sol:A": {"abi": [{"type": "function", "name": "f", "inputs": [], "outputs": [], "stateMutability": "view"}]}}}"#,
+        )
+        .unwrap();
+
+        let generated = Generator::new()
+            .abi(&abi)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains("pub fn f()"));
+        assert!(!source.contains("BYTECODE"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn artifacts() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-artifacts");
+        let artifacts = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/artifacts");
+
+        let generated = Generator::new()
+            .abi(&artifacts)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        assert_eq!(
+            generated,
+            vec![
+                out_dir.join("token.rs"),
+                out_dir.join("counter.rs"),
+                out_dir.join("ownable_upgradeable.rs"),
+            ]
+        );
+
+        let token = fs::read_to_string(&generated[0]).unwrap();
+        assert!(token.contains("pub struct Token;"));
+        assert!(token.contains("pub const BYTECODE: &str = \"6080604052348015"));
+        assert!(token.contains("(\"transfer(address,uint256)\", [0xa9, 0x05, 0x9c, 0xbb]),"));
+
+        let counter = fs::read_to_string(&generated[1]).unwrap();
+        assert!(counter.contains("pub struct Counter;"));
+        assert!(counter.contains("pub const DEPLOYED_BYTECODE: &str = \"6080604052\";"));
+        assert!(counter.contains("pub fn increment(by: solid::int::Uint24)"));
+
+        let ownable = fs::read_to_string(&generated[2]).unwrap();
+        assert!(ownable.contains("pub struct OwnableUpgradeable;"));
+        assert!(!ownable.contains("BYTECODE"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn artifact_file() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-artifact-file");
+        let artifact = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures/artifacts/hardhat/contracts/Counter.sol/Counter.json");
+
+        let generated = Generator::new()
+            .abi(artifact)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()?;
+
+        assert_eq!(generated, vec![out_dir.join("counter.rs")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overloads() -> Result<()> {
+        let out_dir = env::temp_dir().join("solid-build-overloads");
+        let abi = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/overloads.json");
+
+        let generated = Generator::new()
+            .abi(abi)
+            .out_dir(&out_dir)
+            .rename(
+                "safeTransferFrom(address,address,uint256,bytes)",
+                "safeTransferFromWithData",
+            )
+            .rerun_if_changed(false)
+            .generate()?;
+
+        let source = fs::read_to_string(&generated[0]).unwrap();
+        assert!(source.contains(
+            "    /// `safeTransferFrom(address,address,uint256)`\n    #[rustfmt::skip]\n    \
+             #[allow(dead_code)]\n    pub fn safe_transfer_from_address_address_uint256("
+        ));
+        assert!(source.contains("pub fn safe_transfer_from_with_data("));
+        assert!(source.contains("pub fn mint() -> Vec<u8>"));
+        assert!(source.contains("pub fn mint_address(to: solid::Address)"));
+        assert!(source.contains("pub fn decode_mint_uint256_array_output("));
+        assert!(source.contains("pub fn pack_tuple_uint8_bool0("));
+        assert!(source.contains("pub fn pack_tuple_uint8_bool1("));
+        assert!(source.contains("pub struct DepositAddressUint256 {"));
+        assert!(source.contains("    DeniedString(DeniedString<'a>),"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_abi() {
+        let err = Generator::new()
+            .abi("missing.json")
+            .out_dir(env::temp_dir().join("solid-build-missing"))
+            .rerun_if_changed(false)
+            .generate()
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Io { ref path, .. } if path == Path::new("missing.json")));
+    }
+}
@@ -0,0 +1,45 @@
+//! Generates the bindings written by `cargo-solid` from a build script.
+//!
+//! ```rust,no_run
+//! // build.rs
+//! use std::env;
+//!
+//! fn main() -> Result<(), solid_build::Error> {
+//!     solid_build::Generator::new()
+//!         .abi("stateful.json")
+//!         .out_dir(env::var("OUT_DIR").unwrap())
+//!         .nightly(false)
+//!         .generate()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Besides solc's combined and standard JSON output, plain JSON ABIs and Foundry, Hardhat and
+//! Truffle artifacts can be given, as can directories of them such as Foundry's `out`.
+//!
+//! Each contract is written to a file named after it in snake case, which can then be included
+//! from `OUT_DIR`:
+//!
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/stateful_contract.rs"));
+//! ```
+
+pub mod error;
+pub mod generator;
+pub mod solidity_contract;
+pub mod solidity_field;
+pub mod solidity_type;
+
+pub use error::{
+    Error,
+    Result,
+};
+pub use generator::Generator;
+pub use solidity_contract::SolidityContract;
+pub use solidity_field::SolidityField;
+pub use solidity_type::{
+    Numeric,
+    SolidityType,
+    TypeOptions,
+};
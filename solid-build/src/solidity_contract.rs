@@ -0,0 +1,410 @@
+use super::{
+    solidity_field::bytes_literal,
+    SolidityField,
+    SolidityType,
+    TypeOptions,
+};
+use heck::SnakeCase;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SolidityContract {
+    pub filename: PathBuf,
+    pub contract: String,
+    pub fields: Vec<SolidityField>,
+    /// Hex encoded creation bytecode, which may contain library placeholders
+    #[serde(default)]
+    pub bytecode: Option<String>,
+    /// Hex encoded runtime bytecode
+    #[serde(default)]
+    pub deployed_bytecode: Option<String>,
+    /// Hex encoded selectors keyed by function signature
+    #[serde(default)]
+    pub method_identifiers: BTreeMap<String, String>,
+}
+
+impl SolidityContract {
+    /// Gives every overload of a function, event or error a distinct `rust_name`
+    ///
+    /// An overload without parameters keeps its name, the others are suffixed with their
+    /// parameter types, so `safeTransferFrom(address,address,uint256)` becomes
+    /// `safeTransferFromAddressAddressUint256`. Overloads still sharing a name, which only
+    /// tuples can cause, are also suffixed with their position. Entries in `renames`, keyed by
+    /// canonical signature, take precedence.
+    pub fn disambiguate(&mut self, renames: &BTreeMap<String, String>) {
+        let mut overloads = BTreeMap::<_, Vec<usize>>::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            if let ("function" | "event" | "error", Some(name)) =
+                (field.r#type.as_str(), &field.name)
+            {
+                overloads
+                    .entry((field.r#type.clone(), name.clone()))
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        for indices in overloads.values().filter(|indices| indices.len() > 1) {
+            let names = indices
+                .iter()
+                .map(|&index| {
+                    let field = &self.fields[index];
+                    format!(
+                        "{}{}",
+                        field.name.as_deref().unwrap_or_default(),
+                        field.overload_suffix()
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            for (position, (&index, name)) in indices.iter().zip(&names).enumerate() {
+                let name = if names.iter().filter(|other| *other == name).count() > 1 {
+                    format!("{}{}", name, position)
+                } else {
+                    name.clone()
+                };
+
+                self.fields[index].rust_name = Some(name);
+            }
+        }
+
+        for field in &mut self.fields {
+            if let Some(name) = field
+                .signature()
+                .and_then(|signature| renames.get(&signature))
+            {
+                field.rust_name = Some(name.clone());
+            }
+        }
+    }
+
+    /// Names the structs of tuples without an internal type, see `SolidityField::name_structs`
+    pub fn name_structs(&mut self) {
+        for field in &mut self.fields {
+            field.name_structs(&self.contract);
+        }
+    }
+
+    pub fn functions(&self, types: TypeOptions) -> String {
+        let functions = self
+            .fields
+            .iter()
+            .map(|field| field.to_rust_function(types))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "
+pub struct {contract};
+
+impl {contract} {{
+{constants}{functions}
+}}
+",
+            contract = self.contract,
+            constants = self.constants(),
+            functions = functions
+        )
+    }
+
+    /// Bytecode and selectors reported by solc, empty for ABIs without them
+    fn constants(&self) -> String {
+        let mut constants = Vec::new();
+
+        let bytecode = [
+            ("BYTECODE", "Creation bytecode", &self.bytecode),
+            (
+                "DEPLOYED_BYTECODE",
+                "Runtime bytecode",
+                &self.deployed_bytecode,
+            ),
+        ];
+        for (name, doc, bytecode) in bytecode.iter() {
+            if let Some(bytecode) = bytecode.as_ref().filter(|bytecode| !bytecode.is_empty()) {
+                constants.push(format!(
+                    "    /// {}, hex encoded\n    pub const {}: &str = \"{}\";",
+                    doc, name, bytecode
+                ));
+            }
+        }
+
+        if !self.method_identifiers.is_empty() {
+            let selectors = self
+                .method_identifiers
+                .iter()
+                .filter_map(|(signature, selector)| {
+                    let selector = u32::from_str_radix(selector, 16).ok()?;
+                    Some(format!(
+                        "        (\"{}\", {}),\n",
+                        signature,
+                        bytes_literal(&selector.to_be_bytes())
+                    ))
+                })
+                .collect::<String>();
+
+            constants.push(format!(
+                "    /// Function selectors keyed by signature\n    pub const METHOD_IDENTIFIERS: \
+                 &[(&str, [u8; 4])] = &[\n{}    ];",
+                selectors
+            ));
+        }
+
+        if constants.is_empty() {
+            String::new()
+        } else {
+            constants.join("\n\n") + "\n"
+        }
+    }
+
+    pub fn types(&self, types: TypeOptions) -> String {
+        format!(
+            "\
+#[allow(unused_imports)]
+use solid::{{Decode, Encode}};\n\n{}{}{}{}",
+            self.structs(types),
+            self.fields
+                .iter()
+                .filter_map(|field| {
+                    field
+                        .get_output_type(types)
+                        .or_else(|| field.get_event_type(types))
+                        .or_else(|| field.get_error_type(types))
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            self.events(types),
+            self.errors(types)
+        )
+    }
+
+    /// `{Contract}Events` enum decoding a log of any non anonymous event by its first topic
+    pub fn events(&self, types: TypeOptions) -> String {
+        let events = self
+            .fields
+            .iter()
+            .filter(|field| field.r#type == "event" && !field.anonymous)
+            .filter_map(|field| Some((field.binding_name()?, field.has_lifetime(types))))
+            .collect::<Vec<_>>();
+
+        if events.is_empty() {
+            return String::new();
+        }
+
+        let (generics, buf) = if events.iter().any(|(_, lifetime)| *lifetime) {
+            ("<'a>", "'a ")
+        } else {
+            ("", "")
+        };
+
+        let variants = events
+            .iter()
+            .map(|(name, lifetime)| {
+                format!(
+                    "    {name}({name}{}),\n",
+                    if *lifetime { "<'a>" } else { "" },
+                    name = name
+                )
+            })
+            .collect::<String>();
+
+        let decoders = events
+            .iter()
+            .map(|(name, _)| {
+                format!(
+                    "
+        if *topic == {name}::TOPIC {{
+            return Ok({contract}Events::{name}({name}::decode_log(topics, data)?));
+        }}
+",
+                    name = name,
+                    contract = self.contract
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            "
+/// Events of `{contract}`
+pub enum {contract}Events{generics} {{
+{variants}}}
+
+impl{generics} {contract}Events{generics} {{
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_log(topics: &{buf}[[u8; 32]], data: &{buf}[u8]) -> solid::Result<Self> {{
+        let topic = topics.first().ok_or(solid::Error::Eof)?;
+{decoders}
+        Err(solid::Error::Message(\"log does not match any event of `{contract}`\".to_string()))
+    }}
+}}
+",
+            contract = self.contract,
+            generics = generics,
+            variants = variants,
+            buf = buf,
+            decoders = decoders
+        )
+    }
+
+    /// `{Contract}Errors` enum decoding the revert data of any custom error by its selector
+    pub fn errors(&self, types: TypeOptions) -> String {
+        let errors = self
+            .fields
+            .iter()
+            .filter(|field| field.r#type == "error")
+            .filter_map(|field| Some((field.binding_name()?, field.has_lifetime(types))))
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            return String::new();
+        }
+
+        let (generics, buf) = if errors.iter().any(|(_, lifetime)| *lifetime) {
+            ("<'a>", "'a ")
+        } else {
+            ("", "")
+        };
+
+        let variants = errors
+            .iter()
+            .map(|(name, lifetime)| {
+                format!(
+                    "    {name}({name}{}),\n",
+                    if *lifetime { "<'a>" } else { "" },
+                    name = name
+                )
+            })
+            .collect::<String>();
+
+        let decoders = errors
+            .iter()
+            .map(|(name, _)| {
+                format!(
+                    "
+        if selector == {name}::SELECTOR {{
+            return Ok({contract}Errors::{name}({name}::decode_revert(buf)?));
+        }}
+",
+                    name = name,
+                    contract = self.contract
+                )
+            })
+            .collect::<String>();
+
+        format!(
+            "
+/// Custom errors of `{contract}`
+pub enum {contract}Errors{generics} {{
+{variants}}}
+
+impl{generics} {contract}Errors{generics} {{
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_revert(buf: &{buf}[u8]) -> solid::Result<Self> {{
+        let selector = solid::CallData::new(buf)?.selector();
+{decoders}
+        Err(solid::Error::UnknownSelector(selector))
+    }}
+}}
+",
+            contract = self.contract,
+            generics = generics,
+            variants = variants,
+            buf = buf,
+            decoders = decoders
+        )
+    }
+
+    /// Structs of the tuples taken or returned by any function or event, declared once and
+    /// grouped into a module per declaring contract
+    pub fn structs(&self, types: TypeOptions) -> String {
+        fn collect<'a>(
+            structs: &mut BTreeMap<Option<&'a str>, BTreeMap<&'a str, &'a SolidityType>>,
+            params: &'a [SolidityType],
+        ) {
+            for param in params {
+                if let Some((contract, name)) = param.struct_name() {
+                    structs
+                        .entry(contract)
+                        .or_default()
+                        .entry(name)
+                        .or_insert(param);
+                }
+
+                collect(structs, &param.components);
+            }
+        }
+
+        let mut structs = BTreeMap::new();
+        for field in &self.fields {
+            for params in field.inputs.iter().chain(field.outputs.iter()) {
+                collect(&mut structs, params);
+            }
+        }
+
+        structs
+            .into_iter()
+            .map(|(contract, structs)| {
+                let structs = structs
+                    .into_iter()
+                    .map(|(name, ty)| {
+                        let fields = ty
+                            .components
+                            .iter()
+                            .map(|component| {
+                                format!("    pub {},", component.to_rust_declaration(true, types))
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n");
+
+                        format!(
+                            "\
+#[derive(Encode, Decode)]
+pub struct {}{} {{
+{}
+}}
+",
+                            name,
+                            if ty.has_lifetime(types) { "<'a>" } else { "" },
+                            fields
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                match contract {
+                    Some(contract) => format!(
+                        "\
+pub mod {} {{
+    #[allow(unused_imports)]
+    use super::*;
+
+{}}}
+",
+                        contract.to_snake_case(),
+                        indent(&structs)
+                    ),
+                    None => structs,
+                }
+            })
+            .map(|structs| structs + "\n")
+            .collect()
+    }
+}
+
+fn indent(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("    {}\n", line)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,616 @@
+use super::{
+    solidity_type::{
+        split_dimensions,
+        type_to_rust,
+    },
+    SolidityType,
+    TypeOptions,
+};
+use heck::{
+    CamelCase,
+    SnakeCase,
+};
+use serde::Deserialize;
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+/// Entry of a JSON ABI
+///
+/// Keys other than these, such as the `gas` estimates of Vyper ABIs, are ignored.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolidityField {
+    /// Replaced by `stateMutability` in solc 0.4.16
+    pub constant: Option<bool>,
+    pub inputs: Option<Vec<SolidityType>>,
+    pub name: Option<String>,
+    pub outputs: Option<Vec<SolidityType>>,
+    /// Replaced by `stateMutability` in solc 0.4.16
+    pub payable: Option<bool>,
+    /// Empty for events and errors, see `infer_state_mutability` for older ABIs
+    #[serde(default)]
+    pub state_mutability: String,
+    /// Functions may omit their type
+    #[serde(default = "function")]
+    pub r#type: String,
+    #[serde(default)]
+    pub anonymous: bool,
+    /// Name the bindings are generated under when it differs from `name`, as for overloads
+    #[serde(skip)]
+    pub rust_name: Option<String>,
+}
+
+fn function() -> String {
+    "function".to_string()
+}
+
+impl SolidityField {
+    /// `rust_name` if set, otherwise the Solidity name
+    pub fn binding_name(&self) -> Option<&str> {
+        self.rust_name.as_deref().or(self.name.as_deref())
+    }
+
+    /// Suffix telling an overload apart from the others by its parameter types, such as
+    /// `AddressUint256Array` for `(address,uint256[])`
+    pub fn overload_suffix(&self) -> String {
+        self.inputs
+            .iter()
+            .flatten()
+            .map(SolidityType::suffix)
+            .collect()
+    }
+
+    /// Fills in `state_mutability` from `constant` and `payable` for ABIs written before it
+    /// existed
+    pub fn infer_state_mutability(&mut self) {
+        let callable = matches!(
+            self.r#type.as_str(),
+            "function" | "constructor" | "fallback" | "receive"
+        );
+        if !self.state_mutability.is_empty() || !callable {
+            return;
+        }
+
+        let mutability = match (self.payable, self.constant) {
+            (Some(true), _) => "payable",
+            (_, Some(true)) => "view",
+            // `receive` can only be payable, even when the ABI does not say so
+            _ if self.r#type == "receive" => "payable",
+            _ => "nonpayable",
+        };
+
+        self.state_mutability = mutability.to_string();
+    }
+
+    pub fn to_rust_function(&self, types: TypeOptions) -> String {
+        // Arguments left unnamed, as older compilers often did, are named after their position
+        let inputs = if let Some(inputs) = &self.inputs {
+            inputs
+                .iter()
+                .enumerate()
+                .map(|(index, ty)| {
+                    format!(
+                        "{}: {}",
+                        field_name(ty, index),
+                        ty.to_rust_type(false, types)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            String::new()
+        };
+
+        let variables = if let Some(inputs) = &self.inputs {
+            inputs
+                .iter()
+                .enumerate()
+                .map(|(index, input)| format!("\n            .push({})", field_name(input, index)))
+                .collect::<Vec<_>>()
+                .join("")
+        } else {
+            String::new()
+        };
+
+        let function = if let Some(name) = self.binding_name() {
+            name.to_snake_case()
+        } else {
+            "new".to_string()
+        };
+
+        let doc = match self.signature() {
+            Some(signature) => format!("\n    /// `{}`", signature),
+            None => String::new(),
+        };
+
+        // The selector is computed at compile time by `solid::selector!`, with tuples spelled
+        // out from their `components`
+        let name = match self.signature() {
+            Some(signature) => format!(
+                "\n            .selector(solid::selector!(\"{}\"))",
+                signature
+            ),
+            None => "".to_string(),
+        };
+
+        match self.r#type.as_str() {
+            "constructor" | "function" => format!(
+                "{}
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn {}({}) -> Vec<u8> {{
+        solid::Builder::new(){}{}
+            .build()
+    }}{}",
+                doc,
+                function,
+                inputs,
+                variables,
+                name,
+                self.to_rust_decoder(types).unwrap_or_default()
+            ),
+
+            // Called with whatever calldata matches no selector, so there is nothing to encode
+            "fallback" => "
+    /// Calldata of a call to the fallback function, passed through unchanged
+    #[allow(dead_code)]
+    pub fn fallback(calldata: &[u8]) -> Vec<u8> {
+        calldata.to_vec()
+    }"
+            .to_string(),
+
+            "receive" => "
+    /// Calldata of a plain transfer of ether, which the `receive` function is called with
+    #[allow(dead_code)]
+    pub fn receive() -> Vec<u8> {
+        Vec::new()
+    }"
+            .to_string(),
+
+            _ => String::new(),
+        }
+    }
+
+    /// Canonical signature of a function, event or error, such as `open((address,uint256[]))`
+    pub fn signature(&self) -> Option<String> {
+        let types = self
+            .inputs
+            .iter()
+            .flatten()
+            .map(SolidityType::canonical)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Some(format!("{}({})", self.name.as_ref()?, types))
+    }
+
+    /// Names the structs of tuples without an internal type after `contract` and the field
+    pub fn name_structs(&mut self, contract: &str) {
+        let parent = self
+            .binding_name()
+            .unwrap_or(self.r#type.as_str())
+            .to_camel_case();
+
+        for params in self.inputs.iter_mut().chain(self.outputs.iter_mut()) {
+            for (index, param) in params.iter_mut().enumerate() {
+                param.name_structs(contract, &parent, index);
+            }
+        }
+    }
+
+    /// Decoder of the values returned by the function, next to its encoder
+    pub fn to_rust_decoder(&self, types: TypeOptions) -> Option<String> {
+        let (name, ty) = match (self.r#type.as_str(), self.binding_name()) {
+            ("function", Some(name)) => (name, self.get_output_rust_type(types)?),
+            _ => return None,
+        };
+
+        let outputs = self.outputs.as_deref().unwrap_or_default();
+        let lifetime = outputs.iter().any(|output| output.has_lifetime(types));
+
+        // A lone unnamed value is returned as is rather than as a single element tuple
+        let decode = if outputs.len() == 1 && outputs[0].name.is_empty() {
+            "solid::decode::decode_at(buf, 0)"
+        } else {
+            "solid::decode::Decode::decode(buf)"
+        };
+
+        Some(format!(
+            "
+
+    /// Values returned by `{}`
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_{}_output{}(buf: &{}[u8]) -> solid::Result<{}> {{
+        {}
+    }}",
+            self.signature()?,
+            name.to_snake_case(),
+            if lifetime { "<'a>" } else { "" },
+            if lifetime { "'a " } else { "" },
+            ty,
+            decode
+        ))
+    }
+
+    /// Rust type of the values returned by the function
+    ///
+    /// The `Output` struct if every value is named, otherwise the value itself or a tuple.
+    pub fn get_output_rust_type(&self, types: TypeOptions) -> Option<String> {
+        let outputs = match &self.outputs {
+            Some(outputs) if !outputs.is_empty() => outputs,
+            _ => return None,
+        };
+
+        if let Some(name) = self.output_name() {
+            let lifetime = if outputs.iter().any(|output| output.has_lifetime(types)) {
+                "<'a>"
+            } else {
+                ""
+            };
+
+            return Some(format!("{}{}", name, lifetime));
+        }
+
+        let types = outputs
+            .iter()
+            .map(|output| output.to_rust_type(true, types))
+            .collect::<Vec<_>>();
+
+        Some(if types.len() == 1 {
+            types[0].clone()
+        } else {
+            format!("({})", types.join(", "))
+        })
+    }
+
+    /// Name of the `Output` struct, only generated if every returned value is named
+    fn output_name(&self) -> Option<String> {
+        let outputs = self.outputs.as_ref()?;
+        if outputs.is_empty() || outputs.iter().any(|output| output.name.is_empty()) {
+            return None;
+        }
+
+        let name = self.binding_name()?;
+        let mut chars = name.chars();
+        let first = chars.next()?;
+
+        Some(first.to_uppercase().collect::<String>() + chars.as_str() + "Output")
+    }
+
+    pub fn get_output_type(&self, types: TypeOptions) -> Option<String> {
+        let (outputs, name) = match (self.r#type.as_str(), &self.outputs) {
+            ("function", Some(outputs)) => (outputs, self.output_name()?),
+            _ => return None,
+        };
+
+        let lifetime = if outputs.iter().any(|output| output.has_lifetime(types)) {
+            "<'a>"
+        } else {
+            ""
+        };
+
+        let declarations = outputs
+            .iter()
+            .map(|r#type| format!("    pub {},", r#type.to_rust_declaration(true, types)))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(format!(
+            "\
+#[derive(Decode)]
+pub struct {}{} {{
+{}
+}}
+",
+            name, lifetime, declarations
+        ))
+    }
+
+    /// The first parameter type, including those of tuple components, that has no Rust mapping
+    pub fn unsupported_type(&self) -> Option<&str> {
+        self.inputs
+            .iter()
+            .chain(&self.outputs)
+            .flatten()
+            .find_map(SolidityType::unsupported)
+    }
+
+    /// Whether the event or error struct borrows from the log or revert data
+    pub fn has_lifetime(&self, types: TypeOptions) -> bool {
+        self.inputs.iter().flatten().any(|param| {
+            if self.r#type == "event" && param.indexed && is_hashed(param) {
+                types.nightly
+            } else {
+                param.has_lifetime(types)
+            }
+        })
+    }
+
+    /// Event struct, with its signature, first topic and a decoder of its logs
+    ///
+    /// Indexed strings, bytes, arrays and tuples are only stored as the hash of their
+    /// encoding, so they are decoded as `bytes32`.
+    pub fn get_event_type(&self, types: TypeOptions) -> Option<String> {
+        let (name, signature) = match (self.r#type.as_str(), self.binding_name()) {
+            ("event", Some(name)) => (name, self.signature()?),
+            _ => return None,
+        };
+
+        let params = self.inputs.as_deref().unwrap_or_default();
+        let lifetime = self.has_lifetime(types);
+
+        let declarations = params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| {
+                let ty = if param.indexed && is_hashed(param) {
+                    type_to_rust("bytes32", true, types)
+                } else {
+                    param.to_rust_type(true, types)
+                };
+
+                format!("    pub {}: {},", field_name(param, index), ty)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Anonymous events have no signature topic, so their indexed values start at the first
+        let mut topic = if self.anonymous { 0 } else { 1 };
+        let mut data = Vec::new();
+        let values = params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| {
+                let field = field_name(param, index);
+                if param.indexed {
+                    let get = match topic {
+                        0 => "first()".to_string(),
+                        topic => format!("get({})", topic),
+                    };
+                    topic += 1;
+                    format!(
+                        "
+            {}: solid::decode::decode_at(&topics.{}.ok_or(solid::Error::Eof)?[..], 0)?,",
+                        field, get
+                    )
+                } else {
+                    data.push(field.clone());
+                    format!("\n            {},", field)
+                }
+            })
+            .collect::<String>();
+
+        let check = if self.anonymous {
+            String::new()
+        } else {
+            format!(
+                "
+        if topics.first() != Some(&Self::TOPIC) {{
+            return Err(solid::Error::Message(\"log is not a `{}` event\".to_string()));
+        }}
+",
+                name
+            )
+        };
+
+        let data = if data.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "
+        let ({},) = solid::decode::Decode::decode(data)?;
+",
+                data.join(", ")
+            )
+        };
+
+        let (generics, buf) = if lifetime { ("<'a>", "'a ") } else { ("", "") };
+
+        Some(format!(
+            "\
+/// `{signature}`
+pub struct {name}{generics} {{
+{declarations}
+}}
+
+impl{generics} {name}{generics} {{
+    /// Canonical signature of the event
+    pub const SIGNATURE: &'static str = \"{signature}\";
+
+    /// Hash of the signature, the first topic of the event's logs
+    pub const TOPIC: [u8; 32] = {topic};
+
+    #[rustfmt::skip]
+    #[allow(dead_code, unused_variables)]
+    pub fn decode_log(topics: &{buf}[[u8; 32]], data: &{buf}[u8]) -> solid::Result<Self> {{{check}{data}
+        Ok({name} {{{values}
+        }})
+    }}
+}}
+",
+            signature = signature,
+            name = name,
+            generics = generics,
+            declarations = declarations,
+            topic = bytes_literal(&keccak256(&signature)),
+            buf = buf,
+            check = check,
+            data = data,
+            values = values,
+        ))
+    }
+
+    /// Custom error struct, with its signature, selector and a decoder of its revert data
+    pub fn get_error_type(&self, types: TypeOptions) -> Option<String> {
+        let (name, signature) = match (self.r#type.as_str(), self.binding_name()) {
+            ("error", Some(name)) => (name, self.signature()?),
+            _ => return None,
+        };
+
+        let params = self.inputs.as_deref().unwrap_or_default();
+        let lifetime = self.has_lifetime(types);
+        let declarations = params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| {
+                format!(
+                    "\n    pub {}: {},",
+                    field_name(param, index),
+                    param.to_rust_type(true, types)
+                )
+            })
+            .collect::<String>();
+        let declarations = if declarations.is_empty() {
+            declarations
+        } else {
+            declarations + "\n"
+        };
+
+        let (generics, buf) = if lifetime { ("<'a>", "'a ") } else { ("", "") };
+
+        Some(format!(
+            "\
+/// `{signature}`
+#[derive(Decode)]
+pub struct {name}{generics} {{{declarations}}}
+
+impl{generics} {name}{generics} {{
+    /// Canonical signature of the error
+    pub const SIGNATURE: &'static str = \"{signature}\";
+
+    /// Selector prepended to the error's revert data
+    pub const SELECTOR: [u8; 4] = {selector};
+
+    #[rustfmt::skip]
+    #[allow(dead_code)]
+    pub fn decode_revert(buf: &{buf}[u8]) -> solid::Result<Self> {{
+        let revert = solid::CallData::new(buf)?;
+        revert.expect_selector(Self::SELECTOR)?;
+        solid::decode::Decode::decode(revert.args())
+    }}
+}}
+",
+            signature = signature,
+            name = name,
+            generics = generics,
+            declarations = declarations,
+            selector = bytes_literal(&keccak256(&signature)[..4]),
+            buf = buf,
+        ))
+    }
+}
+
+/// Name of the struct field of a parameter, unnamed parameters are named after their index
+fn field_name(param: &SolidityType, index: usize) -> String {
+    if param.name.is_empty() {
+        format!("field{}", index)
+    } else {
+        param.name.clone()
+    }
+}
+
+/// Whether an indexed parameter is stored as the hash of its encoding rather than its value
+fn is_hashed(param: &SolidityType) -> bool {
+    let (base, dimensions) = split_dimensions(&param.r#type);
+    !dimensions.is_empty() || matches!(base, "string" | "bytes" | "tuple")
+}
+
+fn keccak256(signature: &str) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.input(signature);
+    hasher.result().to_vec()
+}
+
+pub(crate) fn bytes_literal(bytes: &[u8]) -> String {
+    let bytes = bytes
+        .iter()
+        .map(|byte| format!("0x{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("[{}]", bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(json: &str) -> SolidityField {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn legacy_mutability() {
+        let mut view = field(r#"{"constant": true, "inputs": [], "name": "f", "outputs": []}"#);
+        view.infer_state_mutability();
+        assert_eq!(view.r#type, "function");
+        assert_eq!(view.state_mutability, "view");
+
+        let mut payable = field(r#"{"type": "fallback", "payable": true}"#);
+        payable.infer_state_mutability();
+        assert_eq!(payable.state_mutability, "payable");
+
+        let mut nonpayable = field(r#"{"type": "constructor", "payable": false, "inputs": []}"#);
+        nonpayable.infer_state_mutability();
+        assert_eq!(nonpayable.state_mutability, "nonpayable");
+
+        let mut pure = field(r#"{"constant": true, "stateMutability": "pure", "name": "f"}"#);
+        pure.infer_state_mutability();
+        assert_eq!(pure.state_mutability, "pure");
+
+        let mut event = field(r#"{"type": "event", "name": "E", "inputs": []}"#);
+        event.infer_state_mutability();
+        assert_eq!(event.state_mutability, "");
+    }
+
+    #[test]
+    fn vyper() {
+        let mut transfer = field(
+            r#"{"name": "transfer", "outputs": [{"type": "bool", "name": "out"}],
+                "inputs": [{"type": "address", "name": "_to"}, {"type": "uint256", "name": "_value"}],
+                "constant": false, "payable": false, "type": "function", "gas": 74714}"#,
+        );
+        transfer.infer_state_mutability();
+        assert_eq!(transfer.state_mutability, "nonpayable");
+        assert!(transfer
+            .to_rust_function(TypeOptions::default())
+            .contains("pub fn transfer(_to: solid::Address, _value: solid::int::Uint256)"));
+    }
+
+    #[test]
+    fn unnamed_inputs() {
+        let function =
+            field(r#"{"name": "f", "inputs": [{"type": "uint8"}, {"type": "bool", "name": ""}]}"#);
+        let source = function.to_rust_function(TypeOptions::default());
+        assert!(source.contains("pub fn f(field0: u8, field1: bool)"));
+        assert!(source.contains(".push(field0)\n            .push(field1)"));
+    }
+
+    #[test]
+    fn fallback_and_receive() {
+        let types = TypeOptions::default();
+        assert!(
+            field(r#"{"type": "fallback", "stateMutability": "nonpayable"}"#)
+                .to_rust_function(types)
+                .contains("pub fn fallback(calldata: &[u8]) -> Vec<u8>")
+        );
+        assert!(
+            field(r#"{"type": "receive", "stateMutability": "payable"}"#)
+                .to_rust_function(types)
+                .contains("pub fn receive() -> Vec<u8>")
+        );
+    }
+
+    #[test]
+    fn topic() {
+        assert_eq!(
+            bytes_literal(&keccak256("Transfer(address,address,uint256)")),
+            "[0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, \
+             0x8d, 0xaa, 0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, \
+             0xf5, 0x23, 0xb3, 0xef]"
+        );
+    }
+}
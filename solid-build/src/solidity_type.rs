@@ -0,0 +1,578 @@
+use heck::{
+    CamelCase,
+    SnakeCase,
+};
+use serde::Deserialize;
+use solid_core::value::SolType;
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolidityType {
+    pub internal_type: Option<String>,
+    #[serde(default)]
+    pub name: String,
+    pub r#type: String,
+    #[serde(default)]
+    pub components: Vec<SolidityType>,
+    /// Whether an event parameter is a topic
+    #[serde(default)]
+    pub indexed: bool,
+}
+
+impl SolidityType {
+    pub fn to_rust_type(&self, lifetime: bool, types: TypeOptions) -> String {
+        let (base, dimensions) = split_dimensions(&self.r#type);
+
+        let ty = if base == "tuple" {
+            self.struct_path(lifetime, types)
+        } else {
+            type_to_rust(base, lifetime, types)
+        };
+
+        dimensions
+            .into_iter()
+            .fold(ty, |ty, dimension| match dimension {
+                Some(len) => format!("[{}; {}]", ty, len),
+                None => format!("Vec<{}>", ty),
+            })
+    }
+
+    pub fn to_rust_declaration(&self, lifetime: bool, types: TypeOptions) -> String {
+        let ty = self.to_rust_type(lifetime, types);
+        format!("{}: {}", self.name, ty)
+    }
+
+    /// Whether the Rust type borrows from the buffer it is decoded from
+    pub fn has_lifetime(&self, types: TypeOptions) -> bool {
+        if self.is_tuple() {
+            return self
+                .components
+                .iter()
+                .any(|component| component.has_lifetime(types));
+        }
+
+        match SolType::parse(&self.r#type) {
+            Ok(ty) => borrows(&ty, types),
+            Err(_) => false,
+        }
+    }
+
+    /// The first type of the parameter or of its components that has no Rust mapping
+    pub fn unsupported(&self) -> Option<&str> {
+        if self.is_tuple() {
+            return self.components.iter().find_map(SolidityType::unsupported);
+        }
+
+        match SolType::parse(&self.r#type) {
+            Ok(_) => None,
+            Err(_) => Some(&self.r#type),
+        }
+    }
+
+    /// Type spelled as a CamelCase word, such as `Uint256Array` for `uint256[]` and
+    /// `TupleStringBool` for `(string,bool)`
+    pub fn suffix(&self) -> String {
+        let (base, dimensions) = split_dimensions(&self.r#type);
+
+        let base = if base == "tuple" {
+            let components = self
+                .components
+                .iter()
+                .map(SolidityType::suffix)
+                .collect::<String>();
+            format!("Tuple{}", components)
+        } else {
+            base.to_camel_case()
+        };
+
+        dimensions.into_iter().fold(base, |suffix, dimension| {
+            format!("{}Array{}", suffix, dimension.unwrap_or_default())
+        })
+    }
+
+    /// Whether the type is a tuple or an array of tuples
+    pub fn is_tuple(&self) -> bool {
+        split_dimensions(&self.r#type).0 == "tuple"
+    }
+
+    /// Type as it appears in signatures, with tuples spelled out from their components
+    pub fn canonical(&self) -> String {
+        if !self.is_tuple() {
+            return self.r#type.clone();
+        }
+
+        let components = self
+            .components
+            .iter()
+            .map(SolidityType::canonical)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("({}){}", components, &self.r#type["tuple".len()..])
+    }
+
+    /// Declaring contract and name of the struct of a tuple, from an internal type such as
+    /// `struct Vault.Position[]`
+    pub fn struct_name(&self) -> Option<(Option<&str>, &str)> {
+        let internal_type = self.internal_type.as_deref()?;
+        if !self.is_tuple() || !internal_type.starts_with("struct ") {
+            return None;
+        }
+
+        let internal_type = split_dimensions(&internal_type["struct ".len()..]).0;
+        Some(match internal_type.rfind('.') {
+            Some(dot) => (Some(&internal_type[..dot]), &internal_type[dot + 1..]),
+            None => (None, internal_type),
+        })
+    }
+
+    /// Path of the struct of a tuple, structs declared by a contract live in a module named
+    /// after it
+    pub fn struct_path(&self, lifetime: bool, types: TypeOptions) -> String {
+        let path = match self.struct_name() {
+            Some((Some(contract), name)) => format!("{}::{}", contract.to_snake_case(), name),
+            Some((None, name)) => name.to_string(),
+            None => String::new(),
+        };
+
+        match (self.has_lifetime(types), lifetime) {
+            (true, true) => format!("{}<'a>", path),
+            (true, false) => format!("{}<'_>", path),
+            (false, _) => path,
+        }
+    }
+
+    /// Names the structs of tuples without an internal type, as written by solc before 0.5.11,
+    /// after the contract, the function or event and the parameter
+    pub fn name_structs(&mut self, contract: &str, parent: &str, index: usize) {
+        if !self.is_tuple() {
+            return;
+        }
+
+        let name = if self.name.is_empty() {
+            format!("{}{}", parent, index)
+        } else {
+            format!("{}{}", parent, self.name.to_camel_case())
+        };
+
+        if self.struct_name().is_none() {
+            self.internal_type = Some(format!("struct {}.{}", contract, name));
+        }
+
+        let name = self
+            .struct_name()
+            .map(|(_, name)| name.to_string())
+            .unwrap_or(name);
+        for (index, component) in self.components.iter_mut().enumerate() {
+            component.name_structs(contract, &name, index);
+        }
+    }
+}
+
+/// Splits `uint256[2][]` into `uint256` and its dimensions, innermost first, where `None` is
+/// a dynamic array
+pub fn split_dimensions(ty: &str) -> (&str, Vec<Option<&str>>) {
+    let (base, dimensions) = ty.split_at(ty.find('[').unwrap_or(ty.len()));
+
+    let dimensions = dimensions
+        .split(']')
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| Some(dimension.trim_start_matches('[')).filter(|len| !len.is_empty()))
+        .collect();
+
+    (base, dimensions)
+}
+
+/// Backing of the integer types that have no primitive of their exact width, such as
+/// `uint24` or `int256`
+///
+/// `fixed<M>x<N>` and `ufixed<M>x<N>` are generated as the integer of their `M` bits holding the
+/// value multiplied by `10^N`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Numeric {
+    /// `solid::int::Uint24` to `solid::int::Uint256` and their signed counterparts
+    #[default]
+    Solid,
+    /// The smallest primitive holding the integer, `solid::int` types past 128 bits
+    Primitive,
+    /// `num_bigint::BigUint` and `num_bigint::BigInt`, requires solid's `bigint` feature
+    BigInt,
+    /// `ethereum_types::U128` and `ethereum_types::U256` for unsigned integers and
+    /// `solid::int` types for signed ones, requires solid's `ethereum_types` feature
+    EthereumTypes,
+}
+
+impl FromStr for Numeric {
+    type Err = String;
+
+    fn from_str(numeric: &str) -> Result<Self, Self::Err> {
+        match numeric {
+            "solid" => Ok(Numeric::Solid),
+            "primitive" => Ok(Numeric::Primitive),
+            "bigint" => Ok(Numeric::BigInt),
+            "ethereum-types" => Ok(Numeric::EthereumTypes),
+            _ => Err(format!(
+                "unknown numeric backing `{}`, expected `solid`, `primitive`, `bigint` or \
+                 `ethereum-types`",
+                numeric
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            Numeric::Solid => "solid",
+            Numeric::Primitive => "primitive",
+            Numeric::BigInt => "bigint",
+            Numeric::EthereumTypes => "ethereum-types",
+        })
+    }
+}
+
+/// Rust types the Solidity types are generated as
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TypeOptions {
+    /// Use `BytesFix<N>` and `Int<N, M>` instead of the stable types
+    pub nightly: bool,
+    pub numeric: Numeric,
+}
+
+/// Rust type of a Solidity type other than a named tuple, empty if `ty` is not a valid type
+///
+/// `lifetime` decides whether borrowed types use the `'a` of the enclosing struct or are
+/// elided as function arguments.
+pub fn type_to_rust(ty: &str, lifetime: bool, types: TypeOptions) -> String {
+    let lifetime = if lifetime { "'a" } else { "'_" };
+
+    match SolType::parse(ty) {
+        Ok(ty) => sol_type_to_rust(&ty, lifetime, types),
+        Err(_) => String::new(),
+    }
+}
+
+fn sol_type_to_rust(ty: &SolType, lifetime: &str, types: TypeOptions) -> String {
+    match ty {
+        SolType::Address => "solid::Address".to_string(),
+        SolType::Bool => "bool".to_string(),
+        SolType::Function => "solid::Function".to_string(),
+        SolType::String if lifetime == "'_" => "&str".to_string(),
+        SolType::String => format!("&{} str", lifetime),
+        SolType::Bytes => format!("solid::Bytes<{}>", lifetime),
+        SolType::FixedBytes(len) if types.nightly => {
+            format!("solid::bytesfix::BytesFix<{}, {}>", lifetime, len)
+        }
+        SolType::FixedBytes(len) => format!("solid::bytesfix::Bytes{}", len),
+        SolType::Int(bits) => integer(true, *bits, lifetime, types),
+        SolType::Uint(bits) => integer(false, *bits, lifetime, types),
+        SolType::Fixed { signed, bits, .. } => integer(*signed, *bits, lifetime, types),
+        SolType::Array(ty) => format!("Vec<{}>", sol_type_to_rust(ty, lifetime, types)),
+        SolType::FixedArray(ty, len) => {
+            format!("[{}; {}]", sol_type_to_rust(ty, lifetime, types), len)
+        }
+        SolType::Tuple(tys) if tys.len() == 1 => {
+            format!("({},)", sol_type_to_rust(&tys[0], lifetime, types))
+        }
+        SolType::Tuple(tys) => {
+            let tys = tys
+                .iter()
+                .map(|ty| sol_type_to_rust(ty, lifetime, types))
+                .collect::<Vec<_>>();
+            format!("({})", tys.join(", "))
+        }
+    }
+}
+
+fn integer(signed: bool, bits: usize, lifetime: &str, types: TypeOptions) -> String {
+    let primitive = |bits: usize| format!("{}{}", if signed { "i" } else { "u" }, bits);
+    if is_primitive(bits) {
+        return primitive(bits);
+    }
+
+    match (types.numeric, signed) {
+        (Numeric::Primitive, _) if bits < 128 => primitive(bits.next_power_of_two()),
+        (Numeric::BigInt, true) => "num_bigint::BigInt".to_string(),
+        (Numeric::BigInt, false) => "num_bigint::BigUint".to_string(),
+        (Numeric::EthereumTypes, false) if bits < 128 => "ethereum_types::U128".to_string(),
+        (Numeric::EthereumTypes, false) => "ethereum_types::U256".to_string(),
+        _ => {
+            let name = format!("{}{}", if signed { "Int" } else { "Uint" }, bits);
+            if types.nightly {
+                format!("solid::int::{}<{}>", name, lifetime)
+            } else {
+                format!("solid::int::{}", name)
+            }
+        }
+    }
+}
+
+fn is_primitive(bits: usize) -> bool {
+    matches!(bits, 8 | 16 | 32 | 64 | 128)
+}
+
+/// Whether the Rust type of `ty` borrows from the buffer it is decoded from
+fn borrows(ty: &SolType, types: TypeOptions) -> bool {
+    match ty {
+        SolType::String | SolType::Bytes => true,
+        SolType::FixedBytes(_) => types.nightly,
+        SolType::Int(bits) | SolType::Uint(bits) | SolType::Fixed { bits, .. } => {
+            let unsigned = !matches!(ty, SolType::Int(_) | SolType::Fixed { signed: true, .. });
+            let wrapped = match types.numeric {
+                Numeric::Solid => true,
+                Numeric::Primitive => *bits > 128,
+                Numeric::BigInt => false,
+                Numeric::EthereumTypes => !unsigned,
+            };
+            types.nightly && wrapped && !is_primitive(*bits)
+        }
+        SolType::Array(ty) | SolType::FixedArray(ty, _) => borrows(ty, types),
+        SolType::Tuple(tys) => tys.iter().any(|ty| borrows(ty, types)),
+        SolType::Address | SolType::Bool | SolType::Function => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tuple(ty: &str, internal_type: Option<&str>) -> SolidityType {
+        SolidityType {
+            internal_type: internal_type.map(ToString::to_string),
+            name: "position".to_string(),
+            r#type: ty.to_string(),
+            components: vec![
+                SolidityType {
+                    internal_type: None,
+                    name: "memo".to_string(),
+                    r#type: "string".to_string(),
+                    components: Vec::new(),
+                    indexed: false,
+                },
+                SolidityType {
+                    internal_type: None,
+                    name: "amounts".to_string(),
+                    r#type: "uint256[]".to_string(),
+                    components: Vec::new(),
+                    indexed: false,
+                },
+            ],
+            indexed: false,
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        assert_eq!(split_dimensions("uint256"), ("uint256", vec![]));
+        assert_eq!(
+            split_dimensions("tuple[2][]"),
+            ("tuple", vec![Some("2"), None])
+        );
+    }
+
+    #[test]
+    fn tuples() {
+        let position = tuple("tuple[]", Some("struct Vault.Position[]"));
+        assert_eq!(position.canonical(), "(string,uint256[])[]");
+        assert_eq!(position.struct_name(), Some((Some("Vault"), "Position")));
+        assert_eq!(
+            position.to_rust_type(true, TypeOptions::default()),
+            "Vec<vault::Position<'a>>"
+        );
+
+        let fee = tuple("tuple[3]", Some("struct Fee[3]"));
+        assert_eq!(
+            fee.to_rust_type(false, TypeOptions::default()),
+            "[Fee<'_>; 3]"
+        );
+    }
+
+    fn stable(numeric: Numeric) -> TypeOptions {
+        TypeOptions {
+            nightly: false,
+            numeric,
+        }
+    }
+
+    fn nightly(numeric: Numeric) -> TypeOptions {
+        TypeOptions {
+            nightly: true,
+            numeric,
+        }
+    }
+
+    #[test]
+    fn elementary() {
+        let types = TypeOptions::default();
+        assert_eq!(type_to_rust("address", true, types), "solid::Address");
+        assert_eq!(type_to_rust("bool", true, types), "bool");
+        assert_eq!(type_to_rust("function", true, types), "solid::Function");
+        assert_eq!(type_to_rust("string", true, types), "&'a str");
+        assert_eq!(type_to_rust("string", false, types), "&str");
+        assert_eq!(type_to_rust("bytes", true, types), "solid::Bytes<'a>");
+        assert_eq!(type_to_rust("bytes", false, types), "solid::Bytes<'_>");
+        assert_eq!(type_to_rust("uint", true, types), "solid::int::Uint256");
+        assert_eq!(type_to_rust("not_a_type", true, types), "");
+    }
+
+    #[test]
+    fn fixed_bytes() {
+        let types = TypeOptions::default();
+        assert_eq!(
+            type_to_rust("bytes1", true, types),
+            "solid::bytesfix::Bytes1"
+        );
+        assert_eq!(
+            type_to_rust("bytes32", true, types),
+            "solid::bytesfix::Bytes32"
+        );
+        assert_eq!(
+            type_to_rust("bytes10", true, nightly(Numeric::Solid)),
+            "solid::bytesfix::BytesFix<'a, 10>"
+        );
+    }
+
+    #[test]
+    fn integers() {
+        let solid = stable(Numeric::Solid);
+        assert_eq!(type_to_rust("uint8", true, solid), "u8");
+        assert_eq!(type_to_rust("int128", true, solid), "i128");
+        assert_eq!(type_to_rust("uint24", true, solid), "solid::int::Uint24");
+        assert_eq!(type_to_rust("int256", true, solid), "solid::int::Int256");
+        assert_eq!(
+            type_to_rust("int24", true, nightly(Numeric::Solid)),
+            "solid::int::Int24<'a>"
+        );
+
+        let primitive = stable(Numeric::Primitive);
+        assert_eq!(type_to_rust("uint24", true, primitive), "u32");
+        assert_eq!(type_to_rust("int72", true, primitive), "i128");
+        assert_eq!(
+            type_to_rust("uint136", true, primitive),
+            "solid::int::Uint136"
+        );
+
+        let bigint = stable(Numeric::BigInt);
+        assert_eq!(type_to_rust("uint64", true, bigint), "u64");
+        assert_eq!(type_to_rust("uint256", true, bigint), "num_bigint::BigUint");
+        assert_eq!(type_to_rust("int24", true, bigint), "num_bigint::BigInt");
+
+        let ethereum_types = stable(Numeric::EthereumTypes);
+        assert_eq!(
+            type_to_rust("uint96", true, ethereum_types),
+            "ethereum_types::U128"
+        );
+        assert_eq!(
+            type_to_rust("uint256", true, ethereum_types),
+            "ethereum_types::U256"
+        );
+        assert_eq!(
+            type_to_rust("int256", true, ethereum_types),
+            "solid::int::Int256"
+        );
+    }
+
+    #[test]
+    fn fixed_point() {
+        let types = TypeOptions::default();
+        assert_eq!(type_to_rust("fixed", true, types), "i128");
+        assert_eq!(type_to_rust("ufixed64x10", true, types), "u64");
+        assert_eq!(type_to_rust("fixed24x2", true, types), "solid::int::Int24");
+        assert_eq!(
+            type_to_rust("ufixed256x18", true, stable(Numeric::BigInt)),
+            "num_bigint::BigUint"
+        );
+    }
+
+    #[test]
+    fn arrays() {
+        let types = TypeOptions::default();
+        assert_eq!(
+            type_to_rust("address[]", true, types),
+            "Vec<solid::Address>"
+        );
+        assert_eq!(type_to_rust("bool[4]", true, types), "[bool; 4]");
+        assert_eq!(
+            type_to_rust("string[2][]", true, types),
+            "Vec<[&'a str; 2]>"
+        );
+        assert_eq!(type_to_rust("uint8[][3]", false, types), "[Vec<u8>; 3]");
+    }
+
+    #[test]
+    fn anonymous_tuples() {
+        let types = TypeOptions::default();
+        assert_eq!(type_to_rust("(uint8)", true, types), "(u8,)");
+        assert_eq!(
+            type_to_rust("tuple(address,bytes)[]", true, types),
+            "Vec<(solid::Address, solid::Bytes<'a>)>"
+        );
+    }
+
+    #[test]
+    fn lifetimes() {
+        let param = |ty: &str| SolidityType {
+            internal_type: None,
+            name: "value".to_string(),
+            r#type: ty.to_string(),
+            components: Vec::new(),
+            indexed: false,
+        };
+
+        let types = TypeOptions::default();
+        assert!(param("string[3]").has_lifetime(types));
+        assert!(param("bytes").has_lifetime(types));
+        assert!(!param("address").has_lifetime(types));
+        assert!(!param("bytes32").has_lifetime(types));
+        assert!(!param("uint24[]").has_lifetime(types));
+        assert!(!param("function").has_lifetime(types));
+
+        assert!(param("bytes32").has_lifetime(nightly(Numeric::Solid)));
+        assert!(param("uint24[]").has_lifetime(nightly(Numeric::Solid)));
+        assert!(!param("uint64").has_lifetime(nightly(Numeric::Solid)));
+        assert!(!param("uint24").has_lifetime(nightly(Numeric::Primitive)));
+        assert!(!param("uint256").has_lifetime(nightly(Numeric::BigInt)));
+        assert!(!param("uint256").has_lifetime(nightly(Numeric::EthereumTypes)));
+        assert!(param("int256").has_lifetime(nightly(Numeric::EthereumTypes)));
+    }
+
+    #[test]
+    fn unsupported() {
+        let mut position = tuple("tuple", None);
+        assert_eq!(position.unsupported(), None);
+
+        position.components[1].r#type = "uint7".to_string();
+        assert_eq!(position.unsupported(), Some("uint7"));
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!("ethereum-types".parse(), Ok(Numeric::EthereumTypes));
+        assert_eq!(Numeric::Primitive.to_string(), "primitive");
+        assert!("u256".parse::<Numeric>().is_err());
+    }
+
+    #[test]
+    fn suffix() {
+        let position = tuple("tuple[2][]", None);
+        assert_eq!(position.suffix(), "TupleStringUint256ArrayArray2Array");
+        assert_eq!(position.components[0].suffix(), "String");
+    }
+
+    #[test]
+    fn name_structs() {
+        let mut order = tuple("tuple", None);
+        order.components.push(tuple("tuple", None));
+        order.name_structs("Vault", "Open", 0);
+
+        assert_eq!(order.struct_name(), Some((Some("Vault"), "OpenPosition")));
+        assert_eq!(
+            order.components[2].struct_name(),
+            Some((Some("Vault"), "OpenPositionPosition"))
+        );
+    }
+}
@@ -0,0 +1,567 @@
+use crate::value::SolType;
+
+/// Parameter of a JSON ABI entry
+///
+/// Tuples are described by their `components`, with `ty` set to `tuple` or an array of
+/// `tuple`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub ty: String,
+    /// Type in the source, e.g. `struct Point` for a derived struct
+    pub internal_type: Option<String>,
+    pub components: Vec<Param>,
+    /// Whether an event parameter is a topic, `None` outside of events
+    pub indexed: Option<bool>,
+}
+
+impl Param {
+    /// Parameter of the Solidity type `ty`
+    ///
+    /// Components of tuples in `ty` have no names, use `IntoType::param` for types which know
+    /// the names of their components.
+    pub fn from_type(name: &str, ty: &str) -> Param {
+        match SolType::parse(ty) {
+            Ok(ty) => Param::from_sol_type(name, &ty),
+            Err(_) => Param {
+                name: name.to_string(),
+                ty: ty.to_string(),
+                internal_type: None,
+                components: Vec::new(),
+                indexed: None,
+            },
+        }
+    }
+
+    fn from_sol_type(name: &str, ty: &SolType) -> Param {
+        match ty {
+            SolType::Tuple(types) => Param {
+                name: name.to_string(),
+                ty: "tuple".to_string(),
+                internal_type: None,
+                components: types
+                    .iter()
+                    .map(|ty| Param::from_sol_type("", ty))
+                    .collect(),
+                indexed: None,
+            },
+            SolType::Array(inner) => Param::from_sol_type(name, inner).array(None),
+            SolType::FixedArray(inner, len) => Param::from_sol_type(name, inner).array(Some(*len)),
+            ty => Param {
+                name: name.to_string(),
+                ty: ty.to_string(),
+                internal_type: Some(ty.to_string()),
+                components: Vec::new(),
+                indexed: None,
+            },
+        }
+    }
+
+    /// Parameter of a tuple with the given components
+    pub fn tuple(name: &str, internal_type: Option<String>, components: Vec<Param>) -> Param {
+        Param {
+            name: name.to_string(),
+            ty: "tuple".to_string(),
+            internal_type,
+            components,
+            indexed: None,
+        }
+    }
+
+    /// Parameter of an array of this parameter's type, with `len` elements if it is fixed
+    pub fn array(self, len: Option<usize>) -> Param {
+        let suffix = match len {
+            Some(len) => format!("[{}]", len),
+            None => "[]".to_string(),
+        };
+
+        Param {
+            ty: format!("{}{}", self.ty, suffix),
+            internal_type: self
+                .internal_type
+                .map(|internal_type| format!("{}{}", internal_type, suffix)),
+            ..self
+        }
+    }
+
+    /// Parameter with the given internal type
+    pub fn internal_type(self, internal_type: &str) -> Param {
+        Param {
+            internal_type: Some(internal_type.to_string()),
+            ..self
+        }
+    }
+
+    /// Event parameter which is or is not a topic
+    pub fn indexed(self, indexed: bool) -> Param {
+        Param {
+            indexed: Some(indexed),
+            ..self
+        }
+    }
+}
+
+/// Kind of a JSON ABI entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryType {
+    Function,
+    Constructor,
+    Event,
+    Error,
+}
+
+impl EntryType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntryType::Function => "function",
+            EntryType::Constructor => "constructor",
+            EntryType::Event => "event",
+            EntryType::Error => "error",
+        }
+    }
+}
+
+/// `stateMutability` of functions and constructors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateMutability {
+    Pure,
+    View,
+    NonPayable,
+    Payable,
+}
+
+impl StateMutability {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StateMutability::Pure => "pure",
+            StateMutability::View => "view",
+            StateMutability::NonPayable => "nonpayable",
+            StateMutability::Payable => "payable",
+        }
+    }
+}
+
+/// Entry of a JSON ABI, describing a function, constructor, event or error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub ty: EntryType,
+    /// Name of the entry, `None` for constructors
+    pub name: Option<String>,
+    pub inputs: Vec<Param>,
+    /// Return values of functions, always empty for other entries
+    pub outputs: Vec<Param>,
+    /// Mutability of functions and constructors, `None` for events and errors
+    pub state_mutability: Option<StateMutability>,
+    /// Whether an event has no signature topic, `None` for other entries
+    pub anonymous: Option<bool>,
+}
+
+/// Types which describe an entry of a contract's JSON ABI
+///
+/// Derive it with `#[derive(Abi)]` on the same structs which derive `Encode`.
+pub trait Abi {
+    fn abi() -> Entry;
+}
+
+/// Collects the entries of a contract into its JSON ABI
+///
+/// ```rust
+/// # use solid_core::{
+/// #     abi::{Abi, Contract, Entry, EntryType, StateMutability},
+/// # };
+/// #
+/// struct Pause;
+///
+/// impl Abi for Pause {
+///     fn abi() -> Entry {
+///         Entry {
+///             ty: EntryType::Function,
+///             name: Some("pause".to_string()),
+///             inputs: Vec::new(),
+///             outputs: Vec::new(),
+///             state_mutability: Some(StateMutability::NonPayable),
+///             anonymous: None,
+///         }
+///     }
+/// }
+///
+/// let contract = Contract::new("Token").push::<Pause>();
+/// assert_eq!(contract.entries()[0].name.as_deref(), Some("pause"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contract {
+    name: String,
+    entries: Vec<Entry>,
+}
+
+impl Contract {
+    pub fn new(name: &str) -> Self {
+        Contract {
+            name: name.to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Add the entry of `T`
+    pub fn push<T: Abi>(self) -> Self {
+        self.entry(T::abi())
+    }
+
+    /// Add an entry which is not described by a type
+    pub fn entry(mut self, entry: Entry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Solidity source of an `interface` declaring the contract's functions, events and errors
+    ///
+    /// Tuples become structs, named after the struct in their internal type or numbered in
+    /// order of appearance if they have none. Constructors cannot be part of an interface and
+    /// are left out.
+    pub fn to_solidity(&self) -> String {
+        let mut structs = Structs::default();
+        let mut members = Vec::new();
+
+        for entry in &self.entries {
+            let location = match entry.ty {
+                EntryType::Function => Some("calldata"),
+                EntryType::Event | EntryType::Error => None,
+                EntryType::Constructor => continue,
+            };
+
+            let inputs = structs.params(&entry.inputs, location);
+            let name = entry.name.as_deref().unwrap_or_default();
+
+            let member = match entry.ty {
+                EntryType::Function => {
+                    let mut function = format!("function {}({}) external", name, inputs);
+                    match entry.state_mutability {
+                        Some(StateMutability::NonPayable) | None => {}
+                        Some(mutability) => {
+                            function.push(' ');
+                            function.push_str(mutability.as_str());
+                        }
+                    }
+                    if !entry.outputs.is_empty() {
+                        let outputs = structs.params(&entry.outputs, Some("memory"));
+                        function.push_str(&format!(" returns ({})", outputs));
+                    }
+                    function
+                }
+                EntryType::Event => {
+                    let anonymous = if entry.anonymous == Some(true) {
+                        " anonymous"
+                    } else {
+                        ""
+                    };
+                    format!("event {}({}){}", name, inputs, anonymous)
+                }
+                _ => format!("error {}({})", name, inputs),
+            };
+
+            members.push(format!("    {};", member));
+        }
+
+        let mut source = format!("interface {} {{\n", self.name);
+        for (name, fields) in &structs.definitions {
+            source.push_str(&format!("    struct {} {{\n", name));
+            for field in fields {
+                source.push_str(&format!("        {};\n", field));
+            }
+            source.push_str("    }\n\n");
+        }
+        for member in members {
+            source.push_str(&member);
+            source.push('\n');
+        }
+        source.push('}');
+        source
+    }
+}
+
+/// Struct definitions needed by the tuples of an interface
+#[derive(Default)]
+struct Structs {
+    definitions: Vec<(String, Vec<String>)>,
+    anonymous: usize,
+}
+
+impl Structs {
+    /// Comma separated declarations of `params`, using `location` for reference types
+    fn params(&mut self, params: &[Param], location: Option<&str>) -> String {
+        params
+            .iter()
+            .map(|param| {
+                let mut declaration = self.ty(param);
+                if let Some(location) = location {
+                    if is_reference(&param.ty) {
+                        declaration.push(' ');
+                        declaration.push_str(location);
+                    }
+                }
+                if param.indexed == Some(true) {
+                    declaration.push_str(" indexed");
+                }
+                if !param.name.is_empty() {
+                    declaration.push(' ');
+                    declaration.push_str(&param.name);
+                }
+                declaration
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Solidity type of `param`, defining the structs of its tuples
+    fn ty(&mut self, param: &Param) -> String {
+        let suffix = match param.ty.strip_prefix("tuple") {
+            Some(suffix) => suffix,
+            None => return param.ty.clone(),
+        };
+
+        let name = match param
+            .internal_type
+            .as_deref()
+            .and_then(|internal_type| internal_type.strip_prefix("struct "))
+        {
+            Some(internal_type) => internal_type.trim_end_matches(suffix).to_string(),
+            None => {
+                self.anonymous += 1;
+                format!("Tuple{}", self.anonymous - 1)
+            }
+        };
+
+        if !self.definitions.iter().any(|(defined, _)| *defined == name) {
+            // Reserve the name first so recursive components don't define it twice
+            let index = self.definitions.len();
+            self.definitions.push((name.clone(), Vec::new()));
+            let fields = param
+                .components
+                .iter()
+                .enumerate()
+                .map(|(index, component)| {
+                    let ty = self.ty(component);
+                    if component.name.is_empty() {
+                        format!("{} field{}", ty, index)
+                    } else {
+                        format!("{} {}", ty, component.name)
+                    }
+                })
+                .collect();
+            self.definitions[index].1 = fields;
+        }
+
+        format!("{}{}", name, suffix)
+    }
+}
+
+/// Whether values of the ABI type `ty` need a data location in Solidity
+fn is_reference(ty: &str) -> bool {
+    ty == "string" || ty == "bytes" || ty.ends_with(']') || ty.starts_with("tuple")
+}
+
+#[cfg(feature = "derive")]
+mod json {
+    use super::{
+        Contract,
+        Entry,
+        Param,
+    };
+    use crate::{
+        Error,
+        Result,
+    };
+    use serde::ser::{
+        Serialize,
+        SerializeStruct,
+        Serializer,
+    };
+    use serde_json::{
+        Map,
+        Value,
+    };
+
+    impl Serialize for Param {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut param = serializer.serialize_struct("Param", 5)?;
+            param.serialize_field("name", &self.name)?;
+            param.serialize_field("type", &self.ty)?;
+            if let Some(internal_type) = &self.internal_type {
+                param.serialize_field("internalType", internal_type)?;
+            }
+            if !self.components.is_empty() {
+                param.serialize_field("components", &self.components)?;
+            }
+            if let Some(indexed) = self.indexed {
+                param.serialize_field("indexed", &indexed)?;
+            }
+            param.end()
+        }
+    }
+
+    impl Serialize for Entry {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut entry = serializer.serialize_struct("Entry", 6)?;
+            entry.serialize_field("type", self.ty.as_str())?;
+            if let Some(name) = &self.name {
+                entry.serialize_field("name", name)?;
+            }
+            entry.serialize_field("inputs", &self.inputs)?;
+            if self.ty == super::EntryType::Function {
+                entry.serialize_field("outputs", &self.outputs)?;
+            }
+            if let Some(state_mutability) = self.state_mutability {
+                entry.serialize_field("stateMutability", state_mutability.as_str())?;
+            }
+            if let Some(anonymous) = self.anonymous {
+                entry.serialize_field("anonymous", &anonymous)?;
+            }
+            entry.end()
+        }
+    }
+
+    /// Serializes as the JSON ABI array of the contract
+    impl Serialize for Contract {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.entries.serialize(serializer)
+        }
+    }
+
+    impl Contract {
+        /// The JSON ABI of the contract, as emitted by `solc --abi`
+        pub fn to_json(&self) -> Result<String> {
+            serde_json::to_string(self).map_err(|err| Error::Message(err.to_string()))
+        }
+
+        /// The contract in the `solc --combined-json abi` format read by `cargo-solid`, with
+        /// `source` as the name of the source file, e.g. `Token.sol`
+        pub fn to_combined_json(&self, source: &str) -> Result<String> {
+            let mut contract = Map::new();
+            contract.insert("abi".to_string(), Value::String(self.to_json()?));
+
+            let mut contracts = Map::new();
+            contracts.insert(format!("{}:{}", source, self.name), Value::Object(contract));
+
+            let mut combined = Map::new();
+            combined.insert("contracts".to_string(), Value::Object(contracts));
+
+            serde_json::to_string(&combined).map_err(|err| Error::Message(err.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        address::Address,
+        into_type::IntoType,
+    };
+
+    #[test]
+    fn param_test() {
+        let param = Param::from_type("orders", "(address,uint256[2])[]");
+        assert_eq!(param.ty, "tuple[]");
+        assert_eq!(param.internal_type, None);
+        assert_eq!(param.components.len(), 2);
+        assert_eq!(param.components[1].ty, "uint256[2]");
+        assert_eq!(
+            param.components[1].internal_type.as_deref(),
+            Some("uint256[2]")
+        );
+
+        let param = <(Address, Vec<u64>)>::param("pair").array(Some(3));
+        assert_eq!(param.ty, "tuple[3]");
+        assert_eq!(param.components[1].ty, "uint64[]");
+    }
+
+    #[test]
+    fn solidity_test() {
+        let contract = Contract::new("IVault")
+            .entry(Entry {
+                ty: EntryType::Function,
+                name: Some("deposit".to_string()),
+                inputs: vec![
+                    Param::from_type("orders", "(address,uint256)[]")
+                        .internal_type("struct Order[]"),
+                    String::param("memo"),
+                ],
+                outputs: vec![<(u64, Vec<u8>)>::param("")],
+                state_mutability: Some(StateMutability::Payable),
+                anonymous: None,
+            })
+            .entry(Entry {
+                ty: EntryType::Event,
+                name: Some("Deposit".to_string()),
+                inputs: vec![Address::param("owner").indexed(true), u64::param("amount")],
+                outputs: Vec::new(),
+                state_mutability: None,
+                anonymous: Some(false),
+            })
+            .entry(Entry {
+                ty: EntryType::Constructor,
+                name: None,
+                inputs: Vec::new(),
+                outputs: Vec::new(),
+                state_mutability: Some(StateMutability::NonPayable),
+                anonymous: None,
+            });
+
+        assert_eq!(
+            contract.to_solidity(),
+            concat!(
+                "interface IVault {\n",
+                "    struct Order {\n",
+                "        address field0;\n",
+                "        uint256 field1;\n",
+                "    }\n",
+                "\n",
+                "    struct Tuple0 {\n",
+                "        uint64 field0;\n",
+                "        uint8[] field1;\n",
+                "    }\n",
+                "\n",
+                "    function deposit(Order[] calldata orders, string calldata memo) external payable returns (Tuple0 memory);\n",
+                "    event Deposit(address indexed owner, uint64 amount);\n",
+                "}",
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn combined_json_test() -> Result<(), anyhow::Error> {
+        let contract = Contract::new("Token").entry(Entry {
+            ty: EntryType::Error,
+            name: Some("Unauthorized".to_string()),
+            inputs: vec![Address::param("caller")],
+            outputs: Vec::new(),
+            state_mutability: None,
+            anonymous: None,
+        });
+
+        let combined: serde_json::Value =
+            serde_json::from_str(&contract.to_combined_json("Token.sol")?)?;
+        let abi = combined["contracts"]["Token.sol:Token"]["abi"]
+            .as_str()
+            .expect("abi is a string");
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(abi)?,
+            serde_json::json!([{
+                "type": "error",
+                "name": "Unauthorized",
+                "inputs": [{ "name": "caller", "type": "address", "internalType": "address" }],
+            }])
+        );
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
     Error,
+    Result,
 };
 use std::{
     borrow::Cow,
@@ -74,8 +78,8 @@ impl Encode for Address {
 }
 
 impl<'a> Decode<'a> for Address {
-    fn decode(buf: &'a [u8]) -> Self {
-        Address(buf[0..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(Address(word(buf, 0)?.try_into()?))
     }
 }
 
@@ -0,0 +1,344 @@
+use crate::{
+    abi::Param,
+    decode::{
+        decode_param,
+        eof,
+        follow,
+        read_usize,
+        Decode,
+    },
+    encode::Encode,
+    into_type::IntoType,
+    Error,
+    Result,
+};
+use std::{
+    borrow::Cow,
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+/// Lazily decoded Solidity dynamic array `T[]`
+///
+/// Decoding an `ArrayRef` only reads the length of the array and, for dynamic elements, checks
+/// that each offset points into the buffer. Elements are decoded one at a time, on demand,
+/// through `get` or by iterating, so huge arrays can be streamed through without allocating.
+/// `ArrayRef` can be used as a field type in `#[derive(Decode)]` structs.
+///
+/// Encoding an `ArrayRef` cannot fail. If one of its dynamic elements does not decode, the
+/// encoding is copied from the underlying buffer, so the element fails to decode again later.
+///
+/// ```rust
+/// # use solid_core::{array::ArrayRef, builder::Builder, decode::Decode};
+/// # fn main() -> solid_core::Result<()> {
+/// let buf = Builder::new().push(vec![1u64, 2, 3]).build();
+///
+/// let array = ArrayRef::<u64>::decode(&buf[32..])?;
+/// assert_eq!(array.len(), 3);
+///
+/// let sum = array.iter().sum::<solid_core::Result<u64>>()?;
+/// assert_eq!(sum, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ArrayRef<'a, T> {
+    buf: &'a [u8],
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> ArrayRef<'a, T>
+where
+    T: Decode<'a> + Encode,
+{
+    /// Number of elements in the array
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decode the element at `index`
+    pub fn get(&self, index: usize) -> Result<T> {
+        if index >= self.len {
+            return Err(Error::Message(format!(
+                "index {} out of bounds for array of length {}",
+                index, self.len
+            )));
+        }
+
+        decode_param(self.buf, index)
+    }
+
+    /// Iterate over the decoded elements
+    pub fn iter(&self) -> DecodeIter<'a, T> {
+        DecodeIter {
+            buf: self.buf,
+            index: 0,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Encoding of the elements, which static elements store in place
+    fn raw(&self) -> &'a [u8] {
+        &self.buf[..self.len * T::head_len() as usize]
+    }
+
+    /// Re-encoded tails of dynamic elements, or `None` if one of them cannot be decoded
+    fn tails(&self) -> Option<Vec<Vec<u8>>> {
+        self.iter()
+            .map(|element| element.ok().map(|element| element.encode_param()))
+            .collect()
+    }
+}
+
+impl<'a, T> Clone for ArrayRef<'a, T> {
+    fn clone(&self) -> Self {
+        ArrayRef {
+            buf: self.buf,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Decode<'a> for ArrayRef<'a, T>
+where
+    T: Decode<'a> + Encode,
+{
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        let len = read_usize(buf)?;
+        let buf = &buf[32..];
+
+        // Every element has a head, so the length can be validated up front.
+        if len > buf.len() / T::head_len().max(1) as usize {
+            return Err(eof(buf, len.saturating_mul(T::head_len() as usize)));
+        }
+
+        // Only the offsets of dynamic elements are checked, decoding them is left to `get`
+        if T::is_dynamic() {
+            for index in 0..len {
+                follow(buf, index)?;
+            }
+        }
+
+        Ok(ArrayRef {
+            buf,
+            len,
+            marker: PhantomData,
+        })
+    }
+}
+
+// Static elements are copied from the underlying buffer as is. Dynamic elements are re-encoded
+// one by one, unless one of them cannot be decoded, in which case the heads are copied along
+// with everything that follows them so that every offset still points at its tail.
+impl<'a, T> Encode for ArrayRef<'a, T>
+where
+    T: Decode<'a> + Encode,
+{
+    fn encode(&self) -> Vec<u8> {
+        let mut buf = vec![0u8; 32];
+        buf[24..32].copy_from_slice(&(self.len as u64).to_be_bytes());
+
+        if !T::is_dynamic() {
+            buf.extend_from_slice(self.raw());
+            return buf;
+        }
+
+        let tails = match self.tails() {
+            Some(tails) => tails,
+            None => {
+                buf.extend_from_slice(self.buf);
+                return buf;
+            }
+        };
+
+        let mut offset = self.len * 32;
+        for tail in &tails {
+            buf.extend_from_slice(&[0u8; 24]);
+            buf.extend_from_slice(&(offset as u64).to_be_bytes());
+            offset += tail.len();
+        }
+
+        buf.extend(tails.concat());
+        buf
+    }
+
+    fn required_len(&self) -> u64 {
+        let len = if !T::is_dynamic() {
+            self.raw().len()
+        } else {
+            match self.tails() {
+                Some(tails) => tails.iter().map(|tail| 32 + tail.len()).sum(),
+                None => self.buf.len(),
+            }
+        };
+
+        32 + len as u64
+    }
+
+    fn is_dynamic() -> bool {
+        true
+    }
+}
+
+impl<'a, T> IntoType for ArrayRef<'a, T>
+where
+    T: IntoType,
+{
+    fn into_type() -> Cow<'static, str> {
+        Cow::Owned(format!("{}[]", T::into_type()))
+    }
+
+    fn param(name: &str) -> Param {
+        T::param(name).array(None)
+    }
+}
+
+impl<'a, T> IntoIterator for ArrayRef<'a, T>
+where
+    T: Decode<'a> + Encode,
+{
+    type Item = Result<T>;
+    type IntoIter = DecodeIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator decoding the elements of an `ArrayRef` one at a time
+pub struct DecodeIter<'a, T> {
+    buf: &'a [u8],
+    index: usize,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> Iterator for DecodeIter<'a, T>
+where
+    T: Decode<'a> + Encode,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let value = decode_param(self.buf, self.index);
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.len);
+        self.next()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for DecodeIter<'a, T> where T: Decode<'a> + Encode {}
+
+impl<'a, T> FusedIterator for DecodeIter<'a, T> where T: Decode<'a> + Encode {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        builder::Builder,
+        bytes::Bytes,
+        decode::follow,
+    };
+
+    #[test]
+    fn array_ref_static_test() -> Result<()> {
+        let buf = Builder::new().push(vec![10u32, 20, 30]).build();
+        let array = ArrayRef::<u32>::decode(follow(&buf, 0)?)?;
+
+        let mut iter = array.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().unwrap()?, 10);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.nth(1).unwrap()?, 30);
+        assert!(iter.next().is_none());
+
+        assert_eq!(array.get(1)?, 20);
+        assert!(array.get(3).is_err());
+        assert_eq!(array.encode(), vec![10u32, 20, 30].encode());
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_ref_dynamic_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(vec!["random string", "what about another one"])
+            .build();
+        let array = ArrayRef::<&str>::decode(follow(&buf, 0)?)?;
+
+        assert_eq!(
+            array.encode(),
+            vec!["random string", "what about another one"].encode()
+        );
+        assert_eq!(
+            array.required_len(),
+            vec!["random string", "what about another one"].required_len()
+        );
+
+        let strings = array.into_iter().collect::<Result<Vec<_>>>()?;
+        assert_eq!(strings, vec!["random string", "what about another one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_ref_invalid_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(vec![Bytes(&[0xaa; 4]), Bytes(&[0xbb; 4])])
+            .build();
+
+        // Claims far more elements than the buffer can hold
+        let mut invalid = buf.clone();
+        invalid[32 + 31] = 0xff;
+        assert!(ArrayRef::<Bytes>::decode(follow(&invalid, 0)?).is_err());
+
+        // Second element's offset points past the end of the buffer
+        let mut invalid = buf.clone();
+        invalid[32 * 3 + 31] = 0xff;
+        assert!(ArrayRef::<Bytes>::decode(follow(&invalid, 0)?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_ref_lazy_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(vec![Bytes(&[0xaa; 4]), Bytes(&[0xbb; 4])])
+            .build();
+
+        // Second element's length runs past the end of the buffer, which only `get` notices
+        let mut invalid = buf.clone();
+        invalid[32 * 6 + 31] = 0xff;
+        let array = ArrayRef::<Bytes>::decode(follow(&invalid, 0)?)?;
+        assert_eq!(array.get(0)?.0, &[0xaa; 4]);
+        assert!(array.get(1).is_err());
+
+        // The elements are copied as is, so the valid one survives re-encoding
+        let encoded = array.encode();
+        assert_eq!(encoded.len() as u64, array.required_len());
+        let array = ArrayRef::<Bytes>::decode(&encoded)?;
+        assert_eq!(array.get(0)?.0, &[0xaa; 4]);
+        assert!(array.get(1).is_err());
+
+        Ok(())
+    }
+}
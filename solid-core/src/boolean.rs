@@ -1,7 +1,13 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
+    into_type::IntoType,
+    Result,
 };
+use std::borrow::Cow;
 
 impl Encode for bool {
     fn encode(&self) -> Vec<u8> {
@@ -12,7 +18,13 @@ impl Encode for bool {
 }
 
 impl<'a> Decode<'a> for bool {
-    fn decode(buf: &'a [u8]) -> Self {
-        buf[31] == 1
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(word(buf, 0)?[31] == 1)
+    }
+}
+
+impl IntoType for bool {
+    fn into_type() -> Cow<'static, str> {
+        Cow::Borrowed("bool")
     }
 }
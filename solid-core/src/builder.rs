@@ -10,6 +10,7 @@ use crate::{
 /// call a Solidity function
 pub struct Builder<'a> {
     name: Option<&'a str>,
+    sig: Option<[u8; 4]>,
     selector: Selector,
     pub(super) params: Vec<(bool, Vec<u8>)>,
 }
@@ -18,6 +19,7 @@ impl<'a> Builder<'a> {
     pub fn new() -> Self {
         Self {
             name: None,
+            sig: None,
             selector: Selector::new(),
             params: Vec::new(),
         }
@@ -29,12 +31,20 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Set the function selector directly, e.g. from `solid::selector!`
+    ///
+    /// Takes precedence over `name`, and avoids hashing the signature when building.
+    pub fn selector(mut self, selector: [u8; 4]) -> Self {
+        self.sig = Some(selector);
+        self
+    }
+
     /// Push an argument to the functions argument list
     ///
     /// Each argument is used to determine the function signature.
     pub fn push<F: Encode + IntoType>(mut self, value: F) -> Self {
         self.selector = self.selector.push::<F>();
-        self.params.push((F::is_dynamic(), value.encode()));
+        self.params.push((F::is_dynamic(), value.encode_param()));
         self
     }
 
@@ -44,14 +54,14 @@ impl<'a> Builder<'a> {
     /// parameters will be encoded. A function name must not be set if a Solidity
     /// contract constructor is to be called.
     pub fn build(self) -> Vec<u8> {
-        let name_offset = if let Some(_) = self.name { 4 } else { 0 };
-
-        let sig = if let Some(name) = self.name {
-            Some(self.selector.build(name))
-        } else {
-            None
+        let sig = match (self.sig, self.name) {
+            (Some(sig), _) => Some(sig),
+            (None, Some(name)) => Some(self.selector.build(name)),
+            (None, None) => None,
         };
 
+        let name_offset = if sig.is_some() { 4 } else { 0 };
+
         let total_len = self.params.iter().map(|param| param.1.len()).sum::<usize>()
             + self
                 .params
@@ -63,17 +73,25 @@ impl<'a> Builder<'a> {
 
         let mut buf: Vec<u8> = vec![0; total_len + name_offset];
 
-        let mut offset: usize = self.params.len() * 32 + name_offset;
+        // Offsets are relative to the start of the arguments, not including the selector.
+        // Static parameters are stored in place, so their head is as long as their encoding.
+        let mut offset: usize = self
+            .params
+            .iter()
+            .map(|(dynamic, bytes)| if *dynamic { 32 } else { bytes.len() })
+            .sum();
 
-        for (index, (dynamic, bytes)) in self.params.into_iter().enumerate() {
+        let mut head = name_offset;
+        for (dynamic, bytes) in self.params {
             if dynamic {
-                buf[index * 32 + 24 + name_offset..(index + 1) * 32 + name_offset]
-                    .copy_from_slice(&(offset as u64).to_be_bytes());
-                buf[offset..offset + bytes.len()].copy_from_slice(&bytes);
-                offset += bytes.len()
-            } else {
-                buf[index * 32 + name_offset..(index + 1) * 32 + name_offset]
+                buf[head + 24..head + 32].copy_from_slice(&(offset as u64).to_be_bytes());
+                buf[offset + name_offset..offset + name_offset + bytes.len()]
                     .copy_from_slice(&bytes);
+                offset += bytes.len();
+                head += 32;
+            } else {
+                buf[head..head + bytes.len()].copy_from_slice(&bytes);
+                head += bytes.len();
             }
         }
 
@@ -1,7 +1,12 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        eof,
+        read_usize,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::borrow::Cow;
 
@@ -31,9 +36,14 @@ impl<'a> Encode for Bytes<'a> {
 }
 
 impl<'a> Decode<'a> for Bytes<'a> {
-    fn decode(buf: &'a [u8]) -> Bytes<'a> {
-        let len = u64::decode(&buf[0..32]);
-        Bytes(&buf[32..32 + len as usize])
+    fn decode(buf: &'a [u8]) -> Result<Bytes<'a>> {
+        let len = read_usize(buf)?;
+        let bytes = buf
+            .get(32..)
+            .and_then(|buf| buf.get(..len))
+            // Ask for the padding too, so a `Reader` consumes the whole encoding
+            .ok_or_else(|| eof(buf, (len.saturating_add(31) / 32 * 32).saturating_add(32)))?;
+        Ok(Bytes(bytes))
     }
 }
 
@@ -74,3 +84,91 @@ impl<'de> serde::de::Visitor<'de> for BytesVisitor {
         Ok(Bytes(value))
     }
 }
+
+/// Owned Solidity type `bytes`
+///
+/// Unlike `Bytes`, decoding copies the bytes out of the buffer, so structs holding a
+/// `BytesBuf` implement `DecodeOwned`. `Vec<u8>` cannot be used for this since it is the
+/// Solidity type `uint8[]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BytesBuf(pub Vec<u8>);
+
+impl BytesBuf {
+    pub fn as_bytes(&self) -> Bytes<'_> {
+        Bytes(&self.0)
+    }
+}
+
+impl From<Vec<u8>> for BytesBuf {
+    fn from(bytes: Vec<u8>) -> Self {
+        BytesBuf(bytes)
+    }
+}
+
+impl<'a> From<Bytes<'a>> for BytesBuf {
+    fn from(bytes: Bytes<'a>) -> Self {
+        BytesBuf(bytes.0.to_vec())
+    }
+}
+
+impl Encode for BytesBuf {
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().encode()
+    }
+
+    fn required_len(&self) -> u64 {
+        self.as_bytes().required_len()
+    }
+
+    fn is_dynamic() -> bool {
+        true
+    }
+}
+
+impl<'a> Decode<'a> for BytesBuf {
+    fn decode(buf: &'a [u8]) -> Result<BytesBuf> {
+        Ok(Bytes::decode(buf)?.into())
+    }
+}
+
+impl IntoType for BytesBuf {
+    fn into_type() -> Cow<'static, str> {
+        Cow::Borrowed("bytes")
+    }
+}
+
+#[cfg(feature = "derive")]
+impl serde::ser::Serialize for BytesBuf {
+    fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+#[cfg(feature = "derive")]
+impl<'de> serde::de::Deserialize<'de> for BytesBuf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(BytesBufVisitor)
+    }
+}
+
+struct BytesBufVisitor;
+
+#[cfg(feature = "derive")]
+impl<'de> serde::de::Visitor<'de> for BytesBufVisitor {
+    type Value = BytesBuf;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("bytes array")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(BytesBuf(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(BytesBuf(value))
+    }
+}
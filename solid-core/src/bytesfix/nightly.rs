@@ -1,7 +1,11 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::{
     array::LengthAtMost32,
@@ -50,8 +54,8 @@ impl<'a, const N: usize> Decode<'a> for BytesFix<'a, N>
 where
     [u8; N]: LengthAtMost32 + LengthAtLeast1,
 {
-    fn decode(buf: &'a [u8]) -> Self {
-        BytesFix::<N>(TryFrom::try_from(&buf[0..N]).unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(BytesFix::<N>(TryFrom::try_from(&word(buf, 0)?[0..N])?))
     }
 }
 
@@ -1,11 +1,16 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::{
     borrow::Cow,
     convert::TryInto,
+    mem,
 };
 
 pub struct Bytes1(pub [u8; 1]);
@@ -52,8 +57,8 @@ macro_rules! impl_into_type_and_encode_bytesfix {
         }
 
         impl<'a> Decode<'a> for $ty {
-            fn decode(buf: &'a [u8]) -> Self {
-                $ty(buf[0..32].try_into().unwrap())
+            fn decode(buf: &'a [u8]) -> Result<Self> {
+                Ok($ty(word(buf, 0)?[0..mem::size_of::<$ty>()].try_into()?))
             }
         }
         impl IntoType for $ty {
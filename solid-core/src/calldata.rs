@@ -0,0 +1,240 @@
+use crate::{
+    decode::Decode,
+    into_type::IntoType,
+    selector::Selector,
+    value::{
+        split_params,
+        SolType,
+        SolValue,
+    },
+    Error,
+    Result,
+};
+use std::{
+    convert::TryInto,
+    fmt,
+    str::FromStr,
+};
+
+/// Function signature parsed at runtime, e.g. `transfer(address,uint256)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub inputs: Vec<SolType>,
+}
+
+impl Signature {
+    pub fn parse(signature: &str) -> Result<Signature> {
+        let signature = signature.trim();
+        let invalid = || Error::Message(format!("invalid function signature: {}", signature));
+
+        let open = signature.find('(').ok_or_else(invalid)?;
+        if !signature.ends_with(')') {
+            return Err(invalid());
+        }
+
+        let name = signature[..open].trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        {
+            return Err(invalid());
+        }
+
+        let inputs = split_params(&signature[open + 1..signature.len() - 1])?
+            .into_iter()
+            .map(SolType::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(Signature {
+            name: name.to_string(),
+            inputs,
+        })
+    }
+
+    /// The 4 byte selector of the canonical form of this signature
+    pub fn selector(&self) -> [u8; 4] {
+        Selector::from_signature(&self.to_string())
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+
+    fn from_str(signature: &str) -> Result<Self> {
+        Signature::parse(signature)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.name, SolType::Tuple(self.inputs.clone()))
+    }
+}
+
+/// Function call payload split into its selector and ABI encoded arguments
+///
+/// ```rust
+/// # use solid_core::{builder::Builder, calldata::CallData};
+/// # fn main() -> solid_core::Result<()> {
+/// let buf = Builder::new()
+///     .name("transfer")
+///     .push("daniel")
+///     .push(10u128)
+///     .build();
+///
+/// let call = CallData::new(&buf)?;
+/// let (to, amount): (&str, u128) = call.decode("transfer")?;
+/// # assert_eq!((to, amount), ("daniel", 10));
+///
+/// // The same arguments, with types only known at runtime
+/// let values = call.decode_signature(&"transfer(string,uint128)".parse()?)?;
+/// # assert_eq!(values.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CallData<'a> {
+    selector: [u8; 4],
+    args: &'a [u8],
+}
+
+impl<'a> CallData<'a> {
+    /// Split `buf` into its 4 byte selector and the encoded arguments
+    ///
+    /// The arguments are not checked here; decoding them fails with `Error::Eof` if they are
+    /// truncated.
+    pub fn new(buf: &'a [u8]) -> Result<Self> {
+        if buf.len() < 4 {
+            return Err(Error::Message(format!(
+                "calldata must contain a 4 byte selector, found {} bytes",
+                buf.len()
+            )));
+        }
+
+        Ok(CallData {
+            selector: buf[..4].try_into()?,
+            args: &buf[4..],
+        })
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        self.selector
+    }
+
+    /// The encoded arguments following the selector
+    pub fn args(&self) -> &'a [u8] {
+        self.args
+    }
+
+    /// Fail with `Error::SelectorMismatch` unless the selector equals `expected`
+    pub fn expect_selector(&self, expected: [u8; 4]) -> Result<()> {
+        if self.selector == expected {
+            Ok(())
+        } else {
+            Err(Error::SelectorMismatch {
+                expected,
+                found: self.selector,
+            })
+        }
+    }
+
+    /// Decode the arguments of a call to function `name` into a tuple or derived struct
+    ///
+    /// The selector is verified against `name` and the Solidity types of `T` first.
+    pub fn decode<T>(&self, name: &str) -> Result<T>
+    where
+        T: Decode<'a> + IntoType,
+    {
+        self.expect_selector(Selector::from_signature(&format!(
+            "{}{}",
+            name,
+            T::into_type()
+        )))?;
+        T::decode(self.args)
+    }
+
+    /// Decode the arguments against a signature that is only known at runtime
+    pub fn decode_signature(&self, signature: &Signature) -> Result<Vec<SolValue<'a>>> {
+        self.expect_selector(signature.selector())?;
+        SolValue::decode_tuple(&signature.inputs, self.args)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::Builder;
+
+    #[test]
+    fn signature_parse_test() -> Result<()> {
+        let signature = Signature::parse("transfer(address, uint)")?;
+        assert_eq!(signature.to_string(), "transfer(address,uint256)");
+        assert_eq!(signature.selector(), [0xa9, 0x05, 0x9c, 0xbb]);
+
+        let signature = Signature::parse("noop()")?;
+        assert!(signature.inputs.is_empty());
+
+        for signature in &[
+            "transfer",
+            "(uint8)",
+            "transfer(uint8",
+            "my func(uint8)",
+            "f(uint7)",
+        ] {
+            assert!(
+                Signature::parse(signature).is_err(),
+                "{} should not parse",
+                signature
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn calldata_decode_test() -> Result<()> {
+        let buf = Builder::new()
+            .name("random_function")
+            .push(10u64)
+            .push("random string")
+            .build();
+
+        let call = CallData::new(&buf)?;
+        assert_eq!(
+            call.selector(),
+            Selector::new()
+                .push::<u64>()
+                .push::<&str>()
+                .build("random_function")
+        );
+
+        let (number, string): (u64, &str) = call.decode("random_function")?;
+        assert_eq!(number, 10);
+        assert_eq!(string, "random string");
+
+        let values = call.decode_signature(&Signature::parse("random_function(uint64,string)")?)?;
+        assert_eq!(values[1], SolValue::String("random string"));
+
+        match call.decode::<(u64, &str)>("other_function") {
+            Err(Error::SelectorMismatch { found, .. }) => assert_eq!(found, call.selector()),
+            _ => panic!("expected a selector mismatch"),
+        }
+        assert!(call.decode::<(u32, &str)>("random_function").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn calldata_invalid_test() {
+        assert!(CallData::new(&[0xa9, 0x05, 0x9c]).is_err());
+        assert!(CallData::new(&[0u8; 4]).is_ok());
+
+        // A truncated argument word is only reported when decoding it
+        let mut buf = Selector::from_signature("f(uint64)").to_vec();
+        buf.extend_from_slice(&[0u8; 31]);
+        let call = CallData::new(&buf).unwrap();
+        assert!(matches!(call.decode::<(u64,)>("f"), Err(Error::Eof)));
+    }
+}
@@ -1,23 +1,146 @@
-use crate::encode::Encode;
+use crate::{
+    array::ArrayRef,
+    encode::Encode,
+    Error,
+    Result,
+};
+use std::{
+    cell::Cell,
+    convert::TryInto,
+};
+
+thread_local! {
+    /// Address one past the byte the last failed bounds check asked for
+    static WANTED: Cell<usize> = Cell::new(0);
+}
 
 /// Declares a type to be decodable from as Solidity response buffer
 pub trait Decode<'a>: Sized {
-    fn decode(buf: &'a [u8]) -> Self;
+    fn decode(buf: &'a [u8]) -> Result<Self>;
+}
+
+/// Types which can be decoded without borrowing from the buffer
+///
+/// Implemented for every type that is `Decode<'a>` for any `'a`, such as `String`, `BytesBuf`,
+/// `Vec`s of owned types and derived structs without lifetimes. Such values can outlive the
+/// buffer they were decoded from.
+pub trait DecodeOwned: for<'a> Decode<'a> {}
+
+impl<T> DecodeOwned for T where T: for<'a> Decode<'a> {}
+
+/// Decodes `buf` into a value which does not borrow from it
+pub fn decode_owned<T: DecodeOwned>(buf: &[u8]) -> Result<T> {
+    T::decode(buf)
+}
+
+/// Returns `Error::Eof` for a read that needed the first `end` bytes of `buf`
+///
+/// The position is remembered so that a `Reader` can buffer exactly as many bytes as decoding
+/// needs, so `Decode` implementations should prefer this over returning `Error::Eof` directly.
+pub fn eof(buf: &[u8], end: usize) -> Error {
+    WANTED.with(|wanted| wanted.set((buf.as_ptr() as usize).saturating_add(end)));
+    Error::Eof
+}
+
+/// Takes the address recorded by the last call to `eof`, or zero if there was none
+pub(crate) fn take_wanted() -> usize {
+    WANTED.with(|wanted| wanted.replace(0))
+}
+
+/// Returns the 32 byte word at `index` within `buf`
+pub fn word(buf: &[u8], index: usize) -> Result<&[u8]> {
+    buf.get(index * 32..(index + 1) * 32)
+        .ok_or_else(|| eof(buf, (index + 1) * 32))
+}
+
+/// Reads the offset or length stored in the first word of `buf`
+///
+/// Fails if the value does not fit into a `usize` instead of silently truncating it.
+pub fn read_usize(buf: &[u8]) -> Result<usize> {
+    let word = word(buf, 0)?;
+    let value = u64::from_be_bytes(word[24..32].try_into()?);
+
+    if word[..24].iter().any(|&byte| byte != 0) || value > usize::MAX as u64 {
+        return Err(Error::Message(format!(
+            "offset or length does not fit into usize: 0x{}",
+            hex::encode(word)
+        )));
+    }
+
+    Ok(value as usize)
+}
+
+/// Checks that the first word of `buf` holds an `int<bits>` or `uint<bits>`
+///
+/// Integers narrower than 256 bits are sign or zero extended to a whole word, so every byte
+/// above the value must repeat its sign bit, or be zero for unsigned integers.
+pub fn check_int(buf: &[u8], bits: usize, signed: bool) -> Result<()> {
+    let word = word(buf, 0)?;
+    let unused = 32 - bits / 8;
+    let extension = if signed && word[unused] & 0x80 != 0 {
+        0xff
+    } else {
+        0
+    };
+
+    if word[..unused].iter().any(|&byte| byte != extension) {
+        return Err(Error::Message(format!(
+            "value does not fit into {}int{}: 0x{}",
+            if signed { "" } else { "u" },
+            bits,
+            hex::encode(word)
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns the buffer starting at the offset stored in the `index`th word of `buf`
+pub fn follow(buf: &[u8], index: usize) -> Result<&[u8]> {
+    let offset = read_usize(word(buf, index)?)?;
+    buf.get(offset..).ok_or_else(|| eof(buf, offset))
+}
+
+/// Returns the encoding of the member whose head starts `offset` bytes into `buf`
+///
+/// Static members are `head_len` bytes stored in place, while dynamic members are found at the
+/// offset stored in their head word.
+pub fn param_at(buf: &[u8], offset: usize, dynamic: bool, head_len: usize) -> Result<&[u8]> {
+    if dynamic {
+        let offset = read_usize(buf.get(offset..).ok_or_else(|| eof(buf, offset + 32))?)?;
+        buf.get(offset..).ok_or_else(|| eof(buf, offset))
+    } else {
+        buf.get(offset..offset + head_len)
+            .ok_or_else(|| eof(buf, offset + head_len))
+    }
+}
+
+/// Decodes the member whose head starts `offset` bytes into `buf`
+pub fn decode_at<'a, T>(buf: &'a [u8], offset: usize) -> Result<T>
+where
+    T: Decode<'a> + Encode,
+{
+    T::decode(param_at(
+        buf,
+        offset,
+        T::is_dynamic(),
+        T::head_len() as usize,
+    )?)
+}
+
+/// Decodes the `index`th element of the array of `T` whose head starts at `buf`
+///
+/// Static types are decoded in place, while dynamic types are decoded from
+/// the tail pointed at by the offset stored in their head word.
+pub fn decode_param<'a, T>(buf: &'a [u8], index: usize) -> Result<T>
+where
+    T: Decode<'a> + Encode,
+{
+    decode_at(buf, index * T::head_len() as usize)
 }
 
 impl<'a, T: Decode<'a> + Encode> Decode<'a> for Vec<T> {
-    fn decode(buf: &'a [u8]) -> Self {
-        let len = u64::decode(&buf[0..32]) as usize;
-
-        let mut vec: Vec<T> = Vec::with_capacity(len);
-        for index in 0..len {
-            if T::is_dynamic() {
-                vec.push(T::decode(&buf[32 + index * 32..32 + (index + 1) * 32]))
-            } else {
-                let offset = u64::decode(&buf[32 + index * 32..32 + (index + 1) * 32]) as usize;
-                vec.push(T::decode(&buf[offset..]))
-            }
-        }
-        vec
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        ArrayRef::<T>::decode(buf)?.iter().collect()
     }
 }
@@ -1,6 +1,12 @@
 use crate::{
     bytes::Bytes,
-    decode::Decode,
+    decode::{
+        decode_param,
+        eof,
+        read_usize,
+        word,
+        Decode,
+    },
     encode::Encode,
     Error,
     Result,
@@ -40,12 +46,7 @@ impl<'de> Deserializer<'de> {
     where
         T: Decode<'de> + Encode,
     {
-        let value = if T::is_dynamic() {
-            let offset = u64::decode(&self.buf[self.index * 32..(self.index + 1) * 32]);
-            T::decode(&self.buf[offset as usize..])
-        } else {
-            T::decode(&self.buf[self.index * 32..(self.index + 1) * 32])
-        };
+        let value = decode_param(self.buf, self.index)?;
         self.index += 1;
         Ok(value)
     }
@@ -181,15 +182,18 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     // passing the visitor an "Access" object that gives it the ability to
     // iterate through the data contained in the sequence.
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        let offset = self.decode::<u64>()? as usize;
-        let len = u64::decode(&self.buf[offset..offset + 32]);
+        let offset = read_usize(word(self.buf, self.index)?)?;
+        self.index += 1;
+
+        let buf = self.buf.get(offset..).ok_or_else(|| eof(self.buf, offset))?;
+        let len = read_usize(buf)?;
 
         let mut deserializer = Deserializer {
-            buf: &self.buf[offset + 32..],
+            buf: &buf[32..],
             index: 0,
         };
 
-        Ok(visitor.visit_seq(VecDeserializer::new(&mut deserializer, len as usize))?)
+        Ok(visitor.visit_seq(VecDeserializer::new(&mut deserializer, len))?)
     }
 
     // Tuples look just like sequences in JSON. Some formats may be able to
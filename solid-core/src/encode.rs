@@ -16,6 +16,23 @@ pub trait Encode {
     fn is_dynamic() -> bool {
         false
     }
+
+    /// The number of bytes the type occupies in the head of an enclosing tuple or array
+    ///
+    /// Dynamic types only store their 32 byte offset in the head. Static types are stored in
+    /// place, which takes more than one word for static tuples such as derived structs without
+    /// dynamic fields.
+    fn head_len() -> u64 {
+        32
+    }
+
+    /// Encodes the value as a member of a tuple or array
+    ///
+    /// This is the same as `encode` for every type except derived calls, which leave out their
+    /// selector when nested in another value.
+    fn encode_param(&self) -> Vec<u8> {
+        self.encode()
+    }
 }
 
 impl<T> Encode for &T
@@ -33,6 +50,14 @@ where
     fn is_dynamic() -> bool {
         T::is_dynamic()
     }
+
+    fn head_len() -> u64 {
+        T::head_len()
+    }
+
+    fn encode_param(&self) -> Vec<u8> {
+        T::encode_param(self)
+    }
 }
 
 impl<T> Encode for Vec<T>
@@ -45,16 +70,17 @@ where
         let mut buf = vec![0u8; len as usize];
         buf[24..32].copy_from_slice(&(self.len() as u64).to_be_bytes());
 
-        let mut offset = self.len() * 32;
+        let head_len = T::head_len() as usize;
+        let mut offset = self.len() * head_len;
 
-        for (index, bytes) in self.into_iter().map(Encode::encode).enumerate() {
+        for (index, bytes) in self.into_iter().map(Encode::encode_param).enumerate() {
             if T::is_dynamic() {
                 buf[32 + index * 32 + 24..32 + (index + 1) * 32]
                     .copy_from_slice(&(offset as u64).to_be_bytes());
                 buf[32 + offset..32 + offset + bytes.len()].copy_from_slice(&bytes);
                 offset += bytes.len()
             } else {
-                buf[32 + index * 32..32 + (index + 1) * 32].copy_from_slice(&bytes);
+                buf[32 + index * head_len..32 + (index + 1) * head_len].copy_from_slice(&bytes);
             }
         }
 
@@ -85,16 +111,17 @@ where
         let mut buf = vec![0u8; len as usize];
         buf[24..32].copy_from_slice(&(self.len() as u64).to_be_bytes());
 
-        let mut offset = self.len() * 32;
+        let head_len = T::head_len() as usize;
+        let mut offset = self.len() * head_len;
 
-        for (index, bytes) in (*self).into_iter().map(Encode::encode).enumerate() {
+        for (index, bytes) in (*self).into_iter().map(Encode::encode_param).enumerate() {
             if T::is_dynamic() {
                 buf[32 + index * 32 + 24..32 + (index + 1) * 32]
                     .copy_from_slice(&(offset as u64).to_be_bytes());
                 buf[32 + offset..32 + offset + bytes.len()].copy_from_slice(&bytes);
                 offset += bytes.len()
             } else {
-                buf[32 + index * 32..32 + (index + 1) * 32].copy_from_slice(&bytes);
+                buf[32 + index * head_len..32 + (index + 1) * head_len].copy_from_slice(&bytes);
             }
         }
 
@@ -17,10 +17,13 @@ pub enum Error {
     Message(String),
     Eof,
     TrailingCharacters,
+    SelectorMismatch { expected: [u8; 4], found: [u8; 4] },
+    UnknownSelector([u8; 4]),
     TryIntoSliceError(#[from] std::array::TryFromSliceError),
     Utf8Error(#[from] std::str::Utf8Error),
     FromUtf8Error(#[from] FromUtf8Error),
     FromHexError(#[from] hex::FromHexError),
+    Io(#[from] std::io::Error),
 }
 
 #[cfg(feature = "derive")]
@@ -39,6 +42,24 @@ impl de::Error for Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str(&self.to_string())
+        match self {
+            Error::Message(message) => formatter.write_str(message),
+            Error::Eof => formatter.write_str("unexpected end of buffer"),
+            Error::TrailingCharacters => formatter.write_str("trailing characters"),
+            Error::SelectorMismatch { expected, found } => write!(
+                formatter,
+                "selector mismatch: expected 0x{}, found 0x{}",
+                hex::encode(expected),
+                hex::encode(found)
+            ),
+            Error::UnknownSelector(selector) => {
+                write!(formatter, "unknown selector: 0x{}", hex::encode(selector))
+            }
+            Error::TryIntoSliceError(err) => err.fmt(formatter),
+            Error::Utf8Error(err) => err.fmt(formatter),
+            Error::FromUtf8Error(err) => err.fmt(formatter),
+            Error::FromHexError(err) => err.fmt(formatter),
+            Error::Io(err) => err.fmt(formatter),
+        }
     }
 }
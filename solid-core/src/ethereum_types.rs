@@ -1,7 +1,11 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use ethereum_types::{
     Address,
@@ -17,8 +21,8 @@ impl Encode for U128 {
 }
 
 impl<'a> Decode<'a> for U128 {
-    fn decode(buf: &[u8]) -> Self {
-        U128::from(u128::decode(buf))
+    fn decode(buf: &[u8]) -> Result<Self> {
+        Ok(U128::from(u128::decode(buf)?))
     }
 }
 
@@ -37,14 +41,14 @@ impl Encode for U256 {
 }
 
 impl<'a> Decode<'a> for U256 {
-    fn decode(buf: &[u8]) -> Self {
-        U256::from(buf)
+    fn decode(buf: &[u8]) -> Result<Self> {
+        Ok(U256::from(word(buf, 0)?))
     }
 }
 
 impl IntoType for U256 {
     fn into_type() -> Cow<'static, str> {
-        Cow::Borrowed("u256")
+        Cow::Borrowed("uint256")
     }
 }
 
@@ -57,10 +61,10 @@ impl Encode for Address {
 }
 
 impl<'a> Decode<'a> for Address {
-    fn decode(buf: &[u8]) -> Self {
+    fn decode(buf: &[u8]) -> Result<Self> {
         let mut address = [0u8; 20];
-        address.copy_from_slice(&buf[12..32]);
-        Address::from(address)
+        address.copy_from_slice(&word(buf, 0)?[12..32]);
+        Ok(Address::from(address))
     }
 }
 
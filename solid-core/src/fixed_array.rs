@@ -0,0 +1,138 @@
+use crate::{
+    abi::Param,
+    decode::{
+        decode_param,
+        Decode,
+    },
+    encode::Encode,
+    into_type::IntoType,
+    Error,
+    Result,
+};
+use std::{
+    borrow::Cow,
+    convert::TryInto,
+};
+
+// Fixed-size arrays `T[N]` are encoded like a tuple of `N` elements of type `T`, so unlike
+// dynamic arrays they have no length prefix and are only dynamic if `T` is.
+macro_rules! impl_fixed_arrays {
+    ($($len:literal)+) => {
+        $(
+            impl<T> Encode for [T; $len]
+            where
+                T: Encode,
+            {
+                fn encode(&self) -> Vec<u8> {
+                    let mut buf = vec![0u8; self.required_len() as usize];
+
+                    let head_len = T::head_len() as usize;
+                    let mut offset = $len * head_len;
+
+                    for (index, bytes) in self.iter().map(Encode::encode_param).enumerate() {
+                        if T::is_dynamic() {
+                            buf[index * 32 + 24..(index + 1) * 32]
+                                .copy_from_slice(&(offset as u64).to_be_bytes());
+                            buf[offset..offset + bytes.len()].copy_from_slice(&bytes);
+                            offset += bytes.len()
+                        } else {
+                            buf[index * head_len..(index + 1) * head_len].copy_from_slice(&bytes);
+                        }
+                    }
+
+                    buf
+                }
+
+                fn required_len(&self) -> u64 {
+                    self.iter().map(Encode::required_len).sum::<u64>()
+                        + if T::is_dynamic() { 32 * $len } else { 0 }
+                }
+
+                fn is_dynamic() -> bool {
+                    T::is_dynamic()
+                }
+
+                fn head_len() -> u64 {
+                    if T::is_dynamic() {
+                        32
+                    } else {
+                        $len * T::head_len()
+                    }
+                }
+            }
+
+            impl<'a, T> Decode<'a> for [T; $len]
+            where
+                T: Decode<'a> + Encode,
+            {
+                fn decode(buf: &'a [u8]) -> Result<Self> {
+                    let elements = (0..$len)
+                        .map(|index| decode_param(buf, index))
+                        .collect::<Result<Vec<T>>>()?;
+
+                    elements.try_into().map_err(|_| Error::Eof)
+                }
+            }
+
+            impl<T> IntoType for [T; $len]
+            where
+                T: IntoType,
+            {
+                fn into_type() -> Cow<'static, str> {
+                    Cow::Owned(format!("{}[{}]", T::into_type(), $len))
+                }
+
+                fn param(name: &str) -> Param {
+                    T::param(name).array(Some($len))
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed_arrays!(
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        builder::Builder,
+        decode::decode_at,
+    };
+
+    #[test]
+    fn static_elements() -> Result<()> {
+        let array = [1u64, 2, 3];
+        assert_eq!("uint64[3]", <[u64; 3]>::into_type());
+        assert!(!<[u64; 3]>::is_dynamic());
+        assert_eq!(96, <[u64; 3]>::head_len());
+
+        // Stored in place, without an offset or a length
+        let buf = Builder::new().push(array).push(4u8).build();
+        assert_eq!(128, buf.len());
+        assert_eq!(3, buf[95]);
+        assert_eq!(4, buf[127]);
+
+        assert_eq!(array, decode_at::<[u64; 3]>(&buf, 0)?);
+        assert_eq!(4u8, decode_at::<u8>(&buf, 96)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dynamic_elements() -> Result<()> {
+        let array = ["a", "bc"];
+        assert_eq!("string[2]", <[&str; 2]>::into_type());
+        assert!(<[&str; 2]>::is_dynamic());
+
+        let buf = Builder::new().push(array).build();
+        let expected = Builder::new().push(("a", "bc")).build();
+        assert_eq!(expected, buf);
+
+        assert_eq!(array, decode_at::<[&str; 2]>(&buf, 0)?);
+
+        Ok(())
+    }
+}
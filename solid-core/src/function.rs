@@ -1,8 +1,12 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
     Error,
+    Result,
 };
 use std::{
     borrow::Cow,
@@ -74,8 +78,8 @@ impl Encode for Function {
 }
 
 impl<'a> Decode<'a> for Function {
-    fn decode(buf: &'a [u8]) -> Self {
-        Function(buf[0..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(Function(word(buf, 0)?.try_into()?))
     }
 }
 
@@ -1,7 +1,11 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use num_bigint::{
     BigInt,
@@ -30,8 +34,8 @@ impl Encode for BigInt {
 }
 
 impl<'a> Decode<'a> for BigInt {
-    fn decode(buf: &[u8]) -> Self {
-        BigInt::from_signed_bytes_be(&buf)
+    fn decode(buf: &[u8]) -> Result<Self> {
+        Ok(BigInt::from_signed_bytes_be(word(buf, 0)?))
     }
 }
 
@@ -60,8 +64,8 @@ impl Encode for BigUint {
 }
 
 impl<'a> Decode<'a> for BigUint {
-    fn decode(buf: &[u8]) -> Self {
-        BigUint::from_bytes_be(&buf)
+    fn decode(buf: &[u8]) -> Result<Self> {
+        Ok(BigUint::from_bytes_be(word(buf, 0)?))
     }
 }
 
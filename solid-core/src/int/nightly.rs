@@ -1,8 +1,12 @@
 use crate::{
     bytesfix::LengthAtLeast1,
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::{
     array::LengthAtMost32,
@@ -42,8 +46,8 @@ impl<'a, const N: usize, const M: usize> Decode<'a> for Int<'a, N, M>
 where
     [u8; M]: LengthAtMost32 + LengthAtLeast1,
 {
-    fn decode(buf: &'a [u8]) -> Self {
-        Int::<N, M>(TryFrom::try_from(&buf[32 - M..32]).unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(Int::<N, M>(TryFrom::try_from(&word(buf, 0)?[32 - M..32])?))
     }
 }
 
@@ -88,8 +92,8 @@ impl<'a, const N: usize, const M: usize> Decode<'a> for Uint<'a, N, M>
 where
     [u8; M]: LengthAtMost32 + LengthAtLeast1,
 {
-    fn decode(buf: &'a [u8]) -> Self {
-        Uint::<N, M>(TryFrom::try_from(&buf[32 - M..32]).unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(Uint::<N, M>(TryFrom::try_from(&word(buf, 0)?[32 - M..32])?))
     }
 }
 
@@ -1,7 +1,11 @@
 use crate::{
-    decode::Decode,
+    decode::{
+        word,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::{
     borrow::Cow,
@@ -50,62 +54,62 @@ impl_encode_signed!(i128);
 impl_encode_unsigned!(u128);
 
 impl<'a> Decode<'a> for i8 {
-    fn decode(buf: &'a [u8]) -> Self {
-        buf[31] as i8
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(word(buf, 0)?[31] as i8)
     }
 }
 
 impl<'a> Decode<'a> for u8 {
-    fn decode(buf: &'a [u8]) -> Self {
-        buf[31]
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(word(buf, 0)?[31])
     }
 }
 
 impl<'a> Decode<'a> for i16 {
-    fn decode(buf: &'a [u8]) -> Self {
-        i16::from_be_bytes(buf[30..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(i16::from_be_bytes(word(buf, 0)?[30..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for u16 {
-    fn decode(buf: &'a [u8]) -> Self {
-        u16::from_be_bytes(buf[30..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(u16::from_be_bytes(word(buf, 0)?[30..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for i32 {
-    fn decode(buf: &'a [u8]) -> Self {
-        i32::from_be_bytes(buf[28..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(i32::from_be_bytes(word(buf, 0)?[28..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for u32 {
-    fn decode(buf: &'a [u8]) -> Self {
-        u32::from_be_bytes(buf[28..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(u32::from_be_bytes(word(buf, 0)?[28..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for i64 {
-    fn decode(buf: &'a [u8]) -> Self {
-        i64::from_be_bytes(buf[24..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(i64::from_be_bytes(word(buf, 0)?[24..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for u64 {
-    fn decode(buf: &'a [u8]) -> Self {
-        u64::from_be_bytes(buf[24..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(u64::from_be_bytes(word(buf, 0)?[24..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for i128 {
-    fn decode(buf: &'a [u8]) -> Self {
-        i128::from_be_bytes(buf[16..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(i128::from_be_bytes(word(buf, 0)?[16..32].try_into()?))
     }
 }
 
 impl<'a> Decode<'a> for u128 {
-    fn decode(buf: &'a [u8]) -> Self {
-        u128::from_be_bytes(buf[16..32].try_into().unwrap())
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(u128::from_be_bytes(word(buf, 0)?[16..32].try_into()?))
     }
 }
 
@@ -176,8 +180,8 @@ macro_rules! impl_encode_int {
         }
 
         impl<'a> Decode<'a> for $ty {
-            fn decode(buf: &'a [u8]) -> Self {
-                $ty(buf[0..32].try_into().unwrap())
+            fn decode(buf: &'a [u8]) -> Result<Self> {
+                Ok($ty(word(buf, 0)?[32 - mem::size_of::<$ty>()..32].try_into()?))
             }
         }
 
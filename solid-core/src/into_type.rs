@@ -1,7 +1,15 @@
+use crate::abi::Param;
 use std::borrow::Cow;
 
 pub trait IntoType {
     fn into_type() -> Cow<'static, str>;
+
+    /// Describes the type as the parameter `name` of a JSON ABI entry
+    ///
+    /// Derived structs and enums override this to name their components and internal type.
+    fn param(name: &str) -> Param {
+        Param::from_type(name, &Self::into_type())
+    }
 }
 
 impl IntoType for i8 {
@@ -71,6 +79,10 @@ where
     fn into_type() -> Cow<'static, str> {
         Cow::Owned(format!("{}[]", T::into_type()))
     }
+
+    fn param(name: &str) -> Param {
+        T::param(name).array(None)
+    }
 }
 
 impl<T> IntoType for Vec<T>
@@ -80,6 +92,10 @@ where
     fn into_type() -> Cow<'static, str> {
         Cow::Owned(format!("{}[]", T::into_type()))
     }
+
+    fn param(name: &str) -> Param {
+        T::param(name).array(None)
+    }
 }
 
 #[cfg(test)]
@@ -2,18 +2,26 @@
 #![cfg_attr(feature = "nightly", feature(const_generic_impls_guard))]
 #![allow(dead_code)]
 
+pub mod abi;
 pub mod address;
+pub mod array;
 pub mod boolean;
 pub mod builder;
 pub mod bytes;
+pub mod calldata;
 pub mod decode;
 pub mod encode;
 pub mod error;
+pub mod fixed_array;
 pub mod function;
 pub mod into_type;
+pub mod reader;
+pub mod registry;
 pub mod selector;
 pub mod string;
 pub mod tuples;
+pub mod value;
+pub mod view;
 
 /// Container for all `bytes<M>` Solidity types
 pub mod bytesfix;
@@ -0,0 +1,317 @@
+use crate::{
+    decode::{
+        self,
+        Decode,
+    },
+    Error,
+    Result,
+};
+use std::io::{
+    self,
+    Read,
+};
+
+#[cfg(feature = "async")]
+use futures_util::io::{
+    AsyncRead,
+    AsyncReadExt,
+};
+
+/// Default upper bound on the number of bytes a `Reader` will buffer
+pub const DEFAULT_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Decodes ABI data incrementally from an `io::Read` (or `AsyncRead` with the "async" feature)
+///
+/// Decoding starts on an empty buffer. Whenever it runs past the end of the bytes read so far,
+/// exactly as many bytes as it asked for are read before decoding again, so the head is read
+/// first and each tail is read once an offset points at it. Nothing past the end of the value
+/// is consumed, provided the `Decode` implementations involved report the missing bytes with
+/// `decode::eof`; for those which return a bare `Error::Eof` one more word is read at a time.
+///
+/// Each time decoding runs out of bytes it starts over from the beginning of the buffer, so a
+/// value with many tails, such as a long `Vec<String>`, is decoded once per tail. The buffer
+/// never grows beyond `limit` bytes.
+///
+/// Only types that do not borrow from the buffer can be decoded this way, e.g. `String`
+/// instead of `&str` and `BytesBuf` instead of `Bytes`.
+///
+/// ```rust
+/// # use solid_core::{builder::Builder, reader::Reader};
+/// # fn main() -> solid_core::Result<()> {
+/// let buf = Builder::new().push(vec!["random string".to_string()]).build();
+///
+/// let mut reader = Reader::new(&buf[..]).limit(4096);
+/// let (strings,): (Vec<String>,) = reader.decode()?;
+/// # let _ = strings;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Reader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    limit: usize,
+    eof: bool,
+}
+
+/// Outcome of decoding the bytes buffered so far
+enum Attempt<T> {
+    Done(Result<T>),
+    /// The buffer has to be grown to this length before decoding again
+    Need(usize),
+}
+
+impl<R> Reader<R> {
+    pub fn new(reader: R) -> Self {
+        Reader {
+            reader,
+            buf: Vec::new(),
+            limit: DEFAULT_LIMIT,
+            eof: false,
+        }
+    }
+
+    /// Set the maximum number of bytes that may be buffered while decoding
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// The bytes that have been read so far
+    pub fn buffered(&self) -> &[u8] {
+        &self.buf
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn try_decode<T>(&self) -> Attempt<T>
+    where
+        T: for<'de> Decode<'de>,
+    {
+        decode::take_wanted();
+
+        match T::decode(&self.buf) {
+            Err(Error::Eof) if !self.eof => {}
+            result => return Attempt::Done(result),
+        }
+
+        let len = self.buf.len();
+        let wanted = decode::take_wanted().saturating_sub(self.buf.as_ptr() as usize);
+        let wanted = if wanted > len {
+            wanted
+        } else {
+            (len + 32).min(self.limit)
+        };
+
+        if wanted > self.limit || wanted <= len {
+            return Attempt::Done(Err(Error::Message(format!(
+                "decoding requires more than the limit of {} bytes",
+                self.limit
+            ))));
+        }
+
+        Attempt::Need(wanted)
+    }
+}
+
+impl<R: Read> Reader<R> {
+    /// Decode a `T`, reading only as much of the underlying reader as required
+    pub fn decode<T>(&mut self) -> Result<T>
+    where
+        T: for<'de> Decode<'de>,
+    {
+        loop {
+            match self.try_decode() {
+                Attempt::Done(result) => return result,
+                Attempt::Need(len) => self.fill(len)?,
+            }
+        }
+    }
+
+    /// Reads until `len` bytes are buffered or the reader is exhausted
+    fn fill(&mut self, len: usize) -> Result<()> {
+        let mut filled = self.buf.len();
+        self.buf.resize(len, 0);
+
+        while filled < len {
+            match self.reader.read(&mut self.buf[filled..]) {
+                Ok(0) => {
+                    self.eof = true;
+                    break;
+                }
+                Ok(read) => filled += read,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    self.buf.truncate(filled);
+                    return Err(err.into());
+                }
+            }
+        }
+
+        self.buf.truncate(filled);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncRead + Unpin> Reader<R> {
+    /// Decode a `T`, reading only as much of the underlying async reader as required
+    pub async fn decode_async<T>(&mut self) -> Result<T>
+    where
+        T: for<'de> Decode<'de>,
+    {
+        loop {
+            match self.try_decode() {
+                Attempt::Done(result) => return result,
+                Attempt::Need(len) => self.fill_async(len).await?,
+            }
+        }
+    }
+
+    async fn fill_async(&mut self, len: usize) -> Result<()> {
+        let mut filled = self.buf.len();
+        self.buf.resize(len, 0);
+
+        while filled < len {
+            match self.reader.read(&mut self.buf[filled..]).await {
+                Ok(0) => {
+                    self.eof = true;
+                    break;
+                }
+                Ok(read) => filled += read,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    self.buf.truncate(filled);
+                    return Err(err.into());
+                }
+            }
+        }
+
+        self.buf.truncate(filled);
+        Ok(())
+    }
+}
+
+/// Decode a `T` from an `io::Read` using the default limit
+pub fn from_reader<T, R>(reader: R) -> Result<T>
+where
+    T: for<'de> Decode<'de>,
+    R: Read,
+{
+    Reader::new(reader).decode()
+}
+
+/// Decode a `T` from an `AsyncRead` using the default limit
+#[cfg(feature = "async")]
+pub async fn from_async_reader<T, R>(reader: R) -> Result<T>
+where
+    T: for<'de> Decode<'de>,
+    R: AsyncRead + Unpin,
+{
+    Reader::new(reader).decode_async().await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        builder::Builder,
+        encode::Encode,
+    };
+
+    /// Reader that hands out at most `chunk` bytes per call and counts what was consumed
+    struct Chunked<'a> {
+        buf: &'a [u8],
+        chunk: usize,
+        consumed: usize,
+    }
+
+    impl<'a> Read for Chunked<'a> {
+        fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+            let len = self.chunk.min(out.len()).min(self.buf.len() - self.consumed);
+            out[..len].copy_from_slice(&self.buf[self.consumed..self.consumed + len]);
+            self.consumed += len;
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn reader_decode_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(7u64)
+            .push(vec!["random string".to_string(), "what about another one".to_string()])
+            .build();
+
+        let mut reader = Reader::new(Chunked {
+            buf: &buf,
+            chunk: 7,
+            consumed: 0,
+        });
+        let (number, strings): (u64, Vec<String>) = reader.decode()?;
+
+        assert_eq!(number, 7);
+        assert_eq!(strings, vec!["random string", "what about another one"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reader_reads_only_what_is_needed_test() -> Result<()> {
+        // A static value followed by a large amount of unrelated data
+        let mut buf = 42u128.encode();
+        buf.extend(vec![0u8; 64 * 1024]);
+
+        let mut reader = Reader::new(Chunked {
+            buf: &buf,
+            chunk: 4096,
+            consumed: 0,
+        });
+
+        assert_eq!(reader.decode::<u128>()?, 42);
+        assert_eq!(reader.into_inner().consumed, 32);
+
+        // Tails are read up to the end of the last one and no further
+        let value = Builder::new()
+            .push(7u64)
+            .push(vec!["random string".to_string(), "what about another one".to_string()])
+            .build();
+        let mut buf = value.clone();
+        buf.extend(vec![0u8; 64 * 1024]);
+
+        let mut reader = Reader::new(Chunked {
+            buf: &buf,
+            chunk: 4096,
+            consumed: 0,
+        });
+
+        let (_, strings): (u64, Vec<String>) = reader.decode()?;
+        assert_eq!(strings.len(), 2);
+        assert_eq!(reader.into_inner().consumed, value.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reader_limit_test() {
+        let buf = Builder::new().push(vec![1u64; 100]).build();
+
+        let result = Reader::new(&buf[..]).limit(512).decode::<(Vec<u64>,)>();
+        assert!(result.is_err());
+
+        let result = Reader::new(&buf[..buf.len() - 32]).decode::<(Vec<u64>,)>();
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn reader_decode_async_test() -> Result<()> {
+        let buf = Builder::new().push(vec!["random string".to_string()]).build();
+
+        let (strings,): (Vec<String>,) =
+            futures_executor::block_on(from_async_reader(futures_util::io::Cursor::new(buf)))?;
+        assert_eq!(strings, vec!["random string"]);
+
+        Ok(())
+    }
+}
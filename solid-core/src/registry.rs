@@ -0,0 +1,259 @@
+use crate::{
+    calldata::{
+        CallData,
+        Signature,
+    },
+    value::SolValue,
+    Error,
+    Result,
+};
+use std::collections::HashMap;
+
+/// Default number of nested calls a `Registry` decodes below the outer call
+pub const DEFAULT_DEPTH: usize = 8;
+
+/// Known function signatures, used to decode calls nested inside `bytes` arguments
+///
+/// Multicalls, multisig and account abstraction wallets and governance proposals wrap the
+/// calldata of inner calls in `bytes` or `bytes[]` arguments. Decoding a call through a
+/// `Registry` replaces every such argument whose selector is known with a `SolValue::Call`,
+/// recursively, up to `depth` levels. Arguments with unknown selectors, or that fail to
+/// decode against the registered signature, are left as raw `SolValue::Bytes`.
+///
+/// ```rust
+/// # use solid_core::{builder::Builder, bytes::Bytes, registry::Registry, value::SolValue};
+/// # fn main() -> solid_core::Result<()> {
+/// let mut registry = Registry::new();
+/// registry.register("transfer(string,uint128)")?;
+/// registry.register("execute(bytes)")?;
+///
+/// let inner = Builder::new().name("transfer").push("daniel").push(10u128).build();
+/// let outer = Builder::new().name("execute").push(Bytes(&inner)).build();
+///
+/// let call = registry.decode(&outer)?;
+/// match &call.args[0] {
+///     SolValue::Call(inner) => assert_eq!(inner.signature.name, "transfer"),
+///     _ => unreachable!(),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Registry {
+    signatures: HashMap<[u8; 4], Signature>,
+    depth: usize,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry {
+            signatures: HashMap::new(),
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    /// Set the maximum number of nested calls decoded below the outer call
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn insert(&mut self, signature: Signature) {
+        self.signatures.insert(signature.selector(), signature);
+    }
+
+    /// Parse and insert a signature such as `transfer(address,uint256)`
+    pub fn register(&mut self, signature: &str) -> Result<()> {
+        self.insert(Signature::parse(signature)?);
+        Ok(())
+    }
+
+    pub fn get(&self, selector: [u8; 4]) -> Option<&Signature> {
+        self.signatures.get(&selector)
+    }
+
+    /// Decode `buf` as a call to a registered function, along with the calls nested in it
+    pub fn decode<'a>(&self, buf: &'a [u8]) -> Result<Call<'a>> {
+        self.decode_call(buf, 0)
+    }
+
+    fn decode_call<'a>(&self, buf: &'a [u8], depth: usize) -> Result<Call<'a>> {
+        let call = CallData::new(buf)?;
+        let selector = call.selector();
+        let signature = self.get(selector).ok_or(Error::UnknownSelector(selector))?;
+
+        let mut args = call.decode_signature(signature)?;
+        if depth < self.depth {
+            for arg in &mut args {
+                self.expand(arg, depth + 1);
+            }
+        }
+
+        Ok(Call {
+            selector,
+            signature: signature.clone(),
+            args,
+        })
+    }
+
+    fn expand<'a>(&self, value: &mut SolValue<'a>, depth: usize) {
+        match value {
+            SolValue::Bytes(bytes) => {
+                if let Ok(call) = self.decode_call(bytes, depth) {
+                    *value = SolValue::Call(Box::new(call));
+                }
+            }
+            SolValue::Array(values) | SolValue::FixedArray(values) | SolValue::Tuple(values) => {
+                for value in values {
+                    self.expand(value, depth);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Decoded function call, possibly containing further calls in its arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call<'a> {
+    pub selector: [u8; 4],
+    pub signature: Signature,
+    pub args: Vec<SolValue<'a>>,
+}
+
+#[cfg(feature = "derive")]
+impl<'a> serde::ser::Serialize for Call<'a> {
+    fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut call = serializer.serialize_struct("Call", 3)?;
+        call.serialize_field("selector", &format!("0x{}", hex::encode(self.selector)))?;
+        call.serialize_field("signature", &self.signature.to_string())?;
+        call.serialize_field("args", &self.args)?;
+        call.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        builder::Builder,
+        bytes::Bytes,
+    };
+
+    fn registry() -> Result<Registry> {
+        let mut registry = Registry::new();
+        registry.register("transfer(string,uint128)")?;
+        registry.register("execute(bytes)")?;
+        registry.register("multicall(bytes[])")?;
+        Ok(registry)
+    }
+
+    #[test]
+    fn registry_nested_test() -> Result<()> {
+        let transfer = Builder::new()
+            .name("transfer")
+            .push("daniel")
+            .push(10u128)
+            .build();
+        let unknown = Builder::new().name("unknown").push(1u8).build();
+        let execute = Builder::new()
+            .name("execute")
+            .push(Bytes(&transfer))
+            .build();
+        let multicall = Builder::new()
+            .name("multicall")
+            .push(vec![Bytes(&execute), Bytes(&unknown)])
+            .build();
+
+        let call = registry()?.decode(&multicall)?;
+        assert_eq!(call.signature.to_string(), "multicall(bytes[])");
+
+        let calls = match &call.args[0] {
+            SolValue::Array(calls) => calls,
+            _ => panic!("expected an array"),
+        };
+
+        let execute = match &calls[0] {
+            SolValue::Call(call) => call,
+            _ => panic!("expected a nested call"),
+        };
+        match &execute.args[0] {
+            SolValue::Call(transfer) => {
+                assert_eq!(transfer.signature.name, "transfer");
+                assert_eq!(transfer.args[0], SolValue::String("daniel"));
+            }
+            _ => panic!("expected a nested call"),
+        }
+
+        assert_eq!(calls[1], SolValue::Bytes(&unknown));
+
+        Ok(())
+    }
+
+    #[test]
+    fn registry_depth_test() -> Result<()> {
+        let transfer = Builder::new()
+            .name("transfer")
+            .push("daniel")
+            .push(10u128)
+            .build();
+        let execute = Builder::new()
+            .name("execute")
+            .push(Bytes(&transfer))
+            .build();
+        let outer = Builder::new().name("execute").push(Bytes(&execute)).build();
+
+        let call = registry()?.depth(1).decode(&outer)?;
+        match &call.args[0] {
+            SolValue::Call(execute) => assert_eq!(execute.args[0], SolValue::Bytes(&transfer)),
+            _ => panic!("expected a nested call"),
+        }
+
+        let call = registry()?.depth(0).decode(&outer)?;
+        assert_eq!(call.args[0], SolValue::Bytes(&execute));
+
+        assert!(Registry::new().decode(&outer).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn registry_json_test() -> Result<()> {
+        let transfer = Builder::new()
+            .name("transfer")
+            .push("daniel")
+            .push(10u128)
+            .build();
+        let execute = Builder::new()
+            .name("execute")
+            .push(Bytes(&transfer))
+            .build();
+
+        let call = registry()?.decode(&execute)?;
+        let json = serde_json::to_value(&call).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "selector": format!("0x{}", hex::encode(call.selector)),
+                "signature": "execute(bytes)",
+                "args": [{
+                    "selector": format!("0x{}", hex::encode(&transfer[..4])),
+                    "signature": "transfer(string,uint128)",
+                    "args": ["daniel", "10"],
+                }],
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -22,9 +22,14 @@ impl Selector {
 
     pub fn build(self, name: &str) -> [u8; 4] {
         let signature = format!("{}({})", name, self.params.join(","));
+        Selector::from_signature(&signature)
+    }
+
+    /// Hash a canonical function signature such as `transfer(address,uint256)`
+    pub fn from_signature(signature: &str) -> [u8; 4] {
         let mut sig = [0; 4];
         let mut hasher = Keccak256::new();
-        hasher.input(&signature);
+        hasher.input(signature);
         sig.copy_from_slice(&hasher.result()[0..4]);
         sig
     }
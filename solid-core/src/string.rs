@@ -1,7 +1,9 @@
 use crate::{
+    bytes::Bytes,
     decode::Decode,
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::borrow::Cow;
 
@@ -28,9 +30,8 @@ impl Encode for String {
 }
 
 impl<'a> Decode<'a> for String {
-    fn decode(buf: &'a [u8]) -> Self {
-        let len = u64::decode(&buf[0..32]);
-        String::from_utf8(buf[32..32 + len as usize].to_vec()).unwrap()
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(String::from_utf8(Bytes::decode(buf)?.0.to_vec())?)
     }
 }
 
@@ -69,8 +70,7 @@ impl IntoType for &str {
 }
 
 impl<'a> Decode<'a> for &'a str {
-    fn decode(buf: &'a [u8]) -> Self {
-        let len = u64::decode(&buf[0..32]);
-        std::str::from_utf8(&buf[32..32 + len as usize]).unwrap()
+    fn decode(buf: &'a [u8]) -> Result<Self> {
+        Ok(std::str::from_utf8(Bytes::decode(buf)?.0)?)
     }
 }
@@ -2,9 +2,15 @@
 use crate::{
     address::Address,
     builder::Builder,
-    bytes::Bytes,
+    bytes::{
+        Bytes,
+        BytesBuf,
+    },
     bytesfix::stable::Bytes4,
+    decode::decode_owned,
+    encode::Encode,
     function::Function,
+    into_type::IntoType,
 };
 #[cfg(test)]
 use std::convert::TryFrom;
@@ -267,15 +273,113 @@ fn hex_test() -> Result<(), anyhow::Error> {
 #[test]
 #[rustfmt::skip]
 fn tuple_test() -> Result<(), anyhow::Error> {
-    let buf = Builder::new().push((0xffu8, 0xaabbu16)).build();
+    // A tuple of static members is static and stored in place
+    let buf = Builder::new().push((0xffu8, 0xaabbu16)).push(1u8).build();
 
-    let offset = hex::decode("0000000000000000000000000000000000000000000000000000000000000020").unwrap();
     let tuple1 = hex::decode("00000000000000000000000000000000000000000000000000000000000000ff").unwrap();
     let tuple2 = hex::decode("000000000000000000000000000000000000000000000000000000000000aabb").unwrap();
+    let last = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+
+    assert_eq!(buf.len(), 32 * 3);
+    assert_eq!(&tuple1[0..32], &buf[32 * 0..32 * 1]);
+    assert_eq!(&tuple2[0..32], &buf[32 * 1..32 * 2]);
+    assert_eq!(&last[0..32], &buf[32 * 2..32 * 3]);
+    assert_eq!((0xffu8, 0xaabbu16, 1u8), decode_owned::<((u8, u16), u8)>(&buf).map(|((a, b), c)| (a, b, c))?);
+
+    // A tuple with a dynamic member is stored in the tail
+    let buf = Builder::new().push((0xffu8, "a")).build();
+
+    let offset = hex::decode("0000000000000000000000000000000000000000000000000000000000000020").unwrap();
+    let string_offset = hex::decode("0000000000000000000000000000000000000000000000000000000000000040").unwrap();
 
     assert_eq!(&offset[0..32], &buf[32 * 0..32 * 1]);
     assert_eq!(&tuple1[0..32], &buf[32 * 1..32 * 2]);
-    assert_eq!(&tuple2[0..32], &buf[32 * 2..32 * 3]);
+    assert_eq!(&string_offset[0..32], &buf[32 * 2..32 * 3]);
+    assert_eq!((0xffu8, "a".to_string()), decode_owned::<((u8, String),)>(&buf)?.0);
+
+    Ok(())
+}
+
+#[test]
+#[rustfmt::skip]
+fn selector_test() {
+    let by_name = Builder::new().name("transfer").push("daniel").push(10u128).build();
+    let by_selector = Builder::new().selector([0xca, 0xc8, 0xc7, 0x1b]).push("daniel").push(10u128).build();
+
+    assert_eq!(&by_name[0..4], &[0xca, 0xc8, 0xc7, 0x1b]);
+    assert_eq!(by_name, by_selector);
+}
+
+#[test]
+fn decode_owned_test() -> Result<(), anyhow::Error> {
+    let buf = Builder::new()
+        .push(BytesBuf(b"owned".to_vec()))
+        .push(vec!["a".to_string(), "b".to_string()])
+        .build();
+
+    let (bytes, strings) = {
+        let copy = buf.clone();
+        decode_owned::<(BytesBuf, Vec<String>)>(&copy)?
+    };
+
+    assert_eq!(bytes, BytesBuf(b"owned".to_vec()));
+    assert_eq!(strings, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(BytesBuf::into_type(), "bytes");
+    assert_eq!(bytes.encode(), Bytes(b"owned").encode());
+    Ok(())
+}
+
+#[test]
+fn static_head_test() -> Result<(), anyhow::Error> {
+    struct Pair(u64, u64);
+
+    impl Encode for Pair {
+        fn encode(&self) -> Vec<u8> {
+            let mut buf = self.0.encode();
+            buf.extend(self.1.encode());
+            buf
+        }
+
+        fn required_len(&self) -> u64 {
+            64
+        }
+
+        fn head_len() -> u64 {
+            64
+        }
+    }
+
+    impl IntoType for Pair {
+        fn into_type() -> std::borrow::Cow<'static, str> {
+            "(uint64,uint64)".into()
+        }
+    }
+
+    impl<'a> crate::decode::Decode<'a> for Pair {
+        fn decode(buf: &'a [u8]) -> crate::Result<Self> {
+            Ok(Pair(
+                crate::decode::decode_at(buf, 0)?,
+                crate::decode::decode_at(buf, 32)?,
+            ))
+        }
+    }
+
+    let buf = Builder::new()
+        .push(Pair(1, 2))
+        .push(vec![Pair(3, 4), Pair(5, 6)])
+        .push(7u64)
+        .build();
+
+    // The pair is stored in place, so the array's offset follows two words of head
+    assert_eq!(buf.len(), 32 * 2 + 32 + 32 + 32 + 32 * 4);
+    assert_eq!(buf[32 * 2 + 31], 128);
 
+    let (pair, pairs, last) = decode_owned::<(Pair, Vec<Pair>, u64)>(&buf)?;
+    assert_eq!((pair.0, pair.1), (1, 2));
+    assert_eq!(
+        pairs.iter().map(|pair| pair.0 + pair.1).collect::<Vec<_>>(),
+        vec![7, 11]
+    );
+    assert_eq!(last, 7);
     Ok(())
 }
@@ -1,7 +1,12 @@
 use crate::{
-    decode::Decode,
+    abi::Param,
+    decode::{
+        decode_at,
+        Decode,
+    },
     encode::Encode,
     into_type::IntoType,
+    Result,
 };
 use std::borrow::Cow;
 
@@ -14,31 +19,29 @@ macro_rules! impl_encode_and_into_types_for_tuples {
 
                 let mut buf: Vec<u8> = vec![0; len as usize];
 
-                let mut count = 0usize;
+                let mut offset = 0usize;
 
                 $(
-                    count += if $ident::is_dynamic() { 1 } else { 1 };
+                    offset += $ident::head_len() as usize;
                 )+
 
-                let mut offset: usize = count * 32;
-
-                let mut index = 0;
+                let mut head = 0usize;
 
                 $(
-                    let bytes = self.$index.encode();
+                    let bytes = self.$index.encode_param();
 
                     if $ident::is_dynamic() {
-                        buf[index * 32 + 24..(index + 1) * 32]
+                        buf[head + 24..head + 32]
                             .copy_from_slice(&(offset as u64).to_be_bytes());
                         buf[offset..offset + bytes.len()]
                             .copy_from_slice(&bytes);
                         offset += bytes.len()
                     } else {
-                        buf[index * 32..(index + 1) * 32]
+                        buf[head..head + bytes.len()]
                             .copy_from_slice(&bytes);
                     }
 
-                    index += 1;
+                    head += $ident::head_len() as usize;
                 )+
 
                 buf
@@ -60,7 +63,15 @@ macro_rules! impl_encode_and_into_types_for_tuples {
             }
 
             fn is_dynamic() -> bool {
-                true
+                false $(|| $ident::is_dynamic())+
+            }
+
+            fn head_len() -> u64 {
+                if Self::is_dynamic() {
+                    32
+                } else {
+                    0 $(+ $ident::head_len())+
+                }
             }
         }
 
@@ -73,34 +84,36 @@ macro_rules! impl_encode_and_into_types_for_tuples {
 
                 Cow::Owned(format!("({})", ty.join(",")))
             }
+
+            fn param(name: &str) -> Param {
+                Param::tuple(name, None, vec![$($ident::param(""), )+])
+            }
         }
 
         #[allow(unused)]
         impl<'a, $($ident: Encode + Decode<'a>, )+> Decode<'a> for ($($ident,) +)
         {
-            fn decode(buf: &'a [u8]) -> Self {
-                let mut index = 0;
+            fn decode(buf: &'a [u8]) -> Result<Self> {
+                let mut head = 0usize;
 
-
-                (
+                Ok((
                     $(
                         {
-                            let value = if $ident::is_dynamic() {
-                                $ident::decode(&buf[index * 32..(index + 1) * 32])
-                            } else {
-                                let offset = u64::decode(&buf[index * 32..(index + 1) * 32]);
-                                $ident::decode(&buf[offset as usize..])
-                            };
-                            index += 1;
+                            let value = decode_at::<$ident>(buf, head)?;
+                            head += $ident::head_len() as usize;
                             value
                         },
                     )+
-                )
+                ))
             }
         }
 	  };
 }
 
+impl_encode_and_into_types_for_tuples!(
+    (0 => T0)
+);
+
 impl_encode_and_into_types_for_tuples!(
     (0 => T0),
     (1 => T1)
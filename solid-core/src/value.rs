@@ -0,0 +1,515 @@
+use crate::{
+    bytes::Bytes,
+    registry::Call,
+    decode::{
+        eof,
+        read_usize,
+        word,
+        Decode,
+    },
+    Error,
+    Result,
+};
+use std::{
+    convert::TryInto,
+    fmt,
+    str::FromStr,
+};
+
+/// Solidity ABI type known only at runtime
+///
+/// Parsed from the canonical type strings used in function signatures, e.g. `uint256`,
+/// `bytes32[]` or `(address,uint256)[2]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolType {
+    Address,
+    Bool,
+    Int(usize),
+    Uint(usize),
+    /// `fixed<M>x<N>` or `ufixed<M>x<N>`; decoded as the raw integer value
+    Fixed {
+        signed: bool,
+        bits: usize,
+        decimals: usize,
+    },
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Function,
+    Array(Box<SolType>),
+    FixedArray(Box<SolType>, usize),
+    Tuple(Vec<SolType>),
+}
+
+impl SolType {
+    pub fn parse(ty: &str) -> Result<SolType> {
+        let ty = ty.trim();
+
+        if ty.ends_with(']') {
+            let open = ty
+                .rfind('[')
+                .ok_or_else(|| Error::Message(format!("invalid Solidity type: {}", ty)))?;
+            let inner = Box::new(SolType::parse(&ty[..open])?);
+            let size = &ty[open + 1..ty.len() - 1];
+
+            return if size.is_empty() {
+                Ok(SolType::Array(inner))
+            } else {
+                let size = size
+                    .parse()
+                    .map_err(|_| Error::Message(format!("invalid array size in type: {}", ty)))?;
+                Ok(SolType::FixedArray(inner, size))
+            };
+        }
+
+        let tuple = if ty.starts_with("tuple(") {
+            Some(&ty[5..])
+        } else if ty.starts_with('(') {
+            Some(ty)
+        } else {
+            None
+        };
+
+        if let Some(tuple) = tuple {
+            if !tuple.ends_with(')') {
+                return Err(Error::Message(format!("invalid Solidity type: {}", ty)));
+            }
+
+            return Ok(SolType::Tuple(
+                split_params(&tuple[1..tuple.len() - 1])?
+                    .into_iter()
+                    .map(SolType::parse)
+                    .collect::<Result<_>>()?,
+            ));
+        }
+
+        parse_elementary(ty).ok_or_else(|| Error::Message(format!("invalid Solidity type: {}", ty)))
+    }
+
+    /// Is this type considered `dynamic` by the ABI specification
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            SolType::Bytes | SolType::String | SolType::Array(_) => true,
+            SolType::FixedArray(ty, _) => ty.is_dynamic(),
+            SolType::Tuple(types) => types.iter().any(SolType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Number of bytes this type takes up in the head of the enclosing tuple
+    pub fn head_len(&self) -> usize {
+        if self.is_dynamic() {
+            return 32;
+        }
+
+        match self {
+            SolType::FixedArray(ty, len) => ty.head_len() * len,
+            SolType::Tuple(types) => types.iter().map(SolType::head_len).sum(),
+            _ => 32,
+        }
+    }
+}
+
+impl FromStr for SolType {
+    type Err = Error;
+
+    fn from_str(ty: &str) -> Result<Self> {
+        SolType::parse(ty)
+    }
+}
+
+impl fmt::Display for SolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolType::Address => f.write_str("address"),
+            SolType::Bool => f.write_str("bool"),
+            SolType::Int(bits) => write!(f, "int{}", bits),
+            SolType::Uint(bits) => write!(f, "uint{}", bits),
+            SolType::Fixed {
+                signed,
+                bits,
+                decimals,
+            } => write!(
+                f,
+                "{}fixed{}x{}",
+                if *signed { "" } else { "u" },
+                bits,
+                decimals
+            ),
+            SolType::FixedBytes(len) => write!(f, "bytes{}", len),
+            SolType::Bytes => f.write_str("bytes"),
+            SolType::String => f.write_str("string"),
+            SolType::Function => f.write_str("function"),
+            SolType::Array(ty) => write!(f, "{}[]", ty),
+            SolType::FixedArray(ty, len) => write!(f, "{}[{}]", ty, len),
+            SolType::Tuple(types) => {
+                f.write_str("(")?;
+                for (index, ty) in types.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", ty)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+fn parse_elementary(ty: &str) -> Option<SolType> {
+    let bits = |bits: &str| -> Option<usize> {
+        let bits = if bits.is_empty() {
+            256
+        } else {
+            bits.parse().ok()?
+        };
+        if bits > 0 && bits <= 256 && bits % 8 == 0 {
+            Some(bits)
+        } else {
+            None
+        }
+    };
+
+    let fixed = |signed: bool, suffix: &str| -> Option<SolType> {
+        let (bits, decimals) = if suffix.is_empty() {
+            (128, 18)
+        } else {
+            let mut split = suffix.splitn(2, 'x');
+            let bits = split.next()?.parse().ok()?;
+            let decimals = split.next()?.parse().ok()?;
+            (bits, decimals)
+        };
+
+        if bits > 0 && bits <= 256 && bits % 8 == 0 && decimals > 0 && decimals <= 80 {
+            Some(SolType::Fixed {
+                signed,
+                bits,
+                decimals,
+            })
+        } else {
+            None
+        }
+    };
+
+    match ty {
+        "address" => Some(SolType::Address),
+        "bool" => Some(SolType::Bool),
+        "bytes" => Some(SolType::Bytes),
+        "string" => Some(SolType::String),
+        "function" => Some(SolType::Function),
+        _ if ty.starts_with("uint") => bits(&ty[4..]).map(SolType::Uint),
+        _ if ty.starts_with("int") => bits(&ty[3..]).map(SolType::Int),
+        _ if ty.starts_with("ufixed") => fixed(false, &ty[6..]),
+        _ if ty.starts_with("fixed") => fixed(true, &ty[5..]),
+        _ if ty.starts_with("bytes") => match ty[5..].parse() {
+            Ok(len) if len > 0 && len <= 32 => Some(SolType::FixedBytes(len)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Split a comma separated parameter list, ignoring commas nested in tuples
+pub(crate) fn split_params(params: &str) -> Result<Vec<&str>> {
+    let mut split = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, c) in params.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| Error::Message(format!("unbalanced parentheses: {}", params)))?
+            }
+            ',' if depth == 0 => {
+                split.push(&params[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err(Error::Message(format!(
+            "unbalanced parentheses: {}",
+            params
+        )));
+    }
+
+    if !params.trim().is_empty() {
+        split.push(&params[start..]);
+    }
+
+    Ok(split)
+}
+
+/// Solidity value decoded against a `SolType` known only at runtime
+///
+/// Integers are kept as their 32 byte big-endian (two's complement) word, and `bytes`
+/// and `string` values borrow from the decoded buffer. `Call` is only produced by a
+/// `Registry`, for `bytes` values that are themselves calldata of a known function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolValue<'a> {
+    Address([u8; 20]),
+    Bool(bool),
+    Int([u8; 32]),
+    Uint([u8; 32]),
+    FixedBytes(&'a [u8]),
+    Bytes(&'a [u8]),
+    String(&'a str),
+    Function([u8; 24]),
+    Array(Vec<SolValue<'a>>),
+    FixedArray(Vec<SolValue<'a>>),
+    Tuple(Vec<SolValue<'a>>),
+    Call(Box<Call<'a>>),
+}
+
+impl<'a> SolValue<'a> {
+    /// Decode a value of type `ty` whose encoding starts at the beginning of `buf`
+    ///
+    /// For dynamic types `buf` must start at the value's tail, i.e. after the offset has
+    /// been followed.
+    pub fn decode(ty: &SolType, buf: &'a [u8]) -> Result<SolValue<'a>> {
+        Ok(match ty {
+            SolType::Address => SolValue::Address(word(buf, 0)?[12..32].try_into()?),
+            SolType::Bool => SolValue::Bool(bool::decode(buf)?),
+            SolType::Int(_) | SolType::Fixed { signed: true, .. } => {
+                SolValue::Int(word(buf, 0)?.try_into()?)
+            }
+            SolType::Uint(_) | SolType::Fixed { .. } => SolValue::Uint(word(buf, 0)?.try_into()?),
+            SolType::FixedBytes(len) => SolValue::FixedBytes(&word(buf, 0)?[..*len]),
+            SolType::Bytes => SolValue::Bytes(Bytes::decode(buf)?.0),
+            SolType::String => SolValue::String(<&str>::decode(buf)?),
+            SolType::Function => SolValue::Function(word(buf, 0)?[..24].try_into()?),
+            SolType::Array(ty) => {
+                let len = read_usize(buf)?;
+                let buf = &buf[32..];
+
+                // Every element takes up at least one word of the head
+                if len > buf.len() / 32 {
+                    return Err(eof(buf, len.saturating_mul(32)));
+                }
+
+                SolValue::Array(decode_sequence((0..len).map(|_| &**ty), buf)?)
+            }
+            SolType::FixedArray(ty, len) => {
+                SolValue::FixedArray(decode_sequence((0..*len).map(|_| &**ty), buf)?)
+            }
+            SolType::Tuple(types) => SolValue::Tuple(decode_sequence(types.iter(), buf)?),
+        })
+    }
+
+    /// Decode the members of a tuple, such as a function's arguments or return data
+    pub fn decode_tuple(types: &[SolType], buf: &'a [u8]) -> Result<Vec<SolValue<'a>>> {
+        decode_sequence(types.iter(), buf)
+    }
+}
+
+/// Format a 32 byte big-endian word as a decimal number
+fn to_decimal(word: &[u8; 32], signed: bool) -> String {
+    let negative = signed && word[0] & 0x80 != 0;
+    let mut value = *word;
+
+    if negative {
+        // Two's complement negation gives the magnitude
+        for byte in value.iter_mut() {
+            *byte = !*byte;
+        }
+        for byte in value.iter_mut().rev() {
+            let (sum, overflow) = byte.overflowing_add(1);
+            *byte = sum;
+            if !overflow {
+                break;
+            }
+        }
+    }
+
+    let mut digits = Vec::new();
+    while value.iter().any(|&byte| byte != 0) {
+        let mut remainder = 0u32;
+        for byte in value.iter_mut() {
+            let current = (remainder << 8) | u32::from(*byte);
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    if negative {
+        digits.push(b'-');
+    }
+
+    digits.into_iter().rev().map(char::from).collect()
+}
+
+// Integers are rendered as decimal strings since they may not fit into a JSON number,
+// while addresses and byte strings are rendered as `0x` prefixed hex.
+#[cfg(feature = "derive")]
+impl<'a> serde::ser::Serialize for SolValue<'a> {
+    fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SolValue::Address(address) => {
+                serializer.serialize_str(&format!("0x{}", hex::encode(address)))
+            }
+            SolValue::Bool(value) => serializer.serialize_bool(*value),
+            SolValue::Int(word) => serializer.serialize_str(&to_decimal(word, true)),
+            SolValue::Uint(word) => serializer.serialize_str(&to_decimal(word, false)),
+            SolValue::FixedBytes(bytes) | SolValue::Bytes(bytes) => {
+                serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+            }
+            SolValue::String(string) => serializer.serialize_str(string),
+            SolValue::Function(function) => {
+                serializer.serialize_str(&format!("0x{}", hex::encode(&function[..])))
+            }
+            SolValue::Array(values) | SolValue::FixedArray(values) | SolValue::Tuple(values) => {
+                serializer.collect_seq(values)
+            }
+            SolValue::Call(call) => call.serialize(serializer),
+        }
+    }
+}
+
+fn decode_sequence<'a, 't, I>(types: I, buf: &'a [u8]) -> Result<Vec<SolValue<'a>>>
+where
+    I: Iterator<Item = &'t SolType>,
+{
+    let mut pos = 0;
+    let mut values = Vec::new();
+
+    for ty in types {
+        let head = buf.get(pos..).ok_or_else(|| eof(buf, pos))?;
+        let value = if ty.is_dynamic() {
+            let offset = read_usize(head)?;
+            SolValue::decode(ty, buf.get(offset..).ok_or_else(|| eof(buf, offset))?)?
+        } else {
+            SolValue::decode(ty, head)?
+        };
+
+        values.push(value);
+        pos += ty.head_len();
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::Builder;
+
+    #[test]
+    fn sol_type_parse_test() -> Result<()> {
+        for ty in &[
+            "address",
+            "bool",
+            "uint8",
+            "int256",
+            "bytes1",
+            "bytes32",
+            "bytes",
+            "string",
+            "function",
+            "fixed128x18",
+            "ufixed8x1",
+            "uint256[]",
+            "bytes32[3]",
+            "(address,uint256)",
+            "(address,(bytes,string[])[2])[]",
+            "()",
+        ] {
+            assert_eq!(SolType::parse(ty)?.to_string(), *ty);
+        }
+
+        assert_eq!(SolType::parse("uint")?, SolType::Uint(256));
+        assert_eq!(
+            SolType::parse("tuple(int,bool)")?.to_string(),
+            "(int256,bool)"
+        );
+
+        for ty in &[
+            "uint7", "uint264", "bytes0", "bytes33", "(uint8", "uint8]", "foo", "uint8[x]",
+        ] {
+            assert!(SolType::parse(ty).is_err(), "{} should not parse", ty);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sol_value_decode_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(0xffu8)
+            .push("random string")
+            .push(vec![1u16, 2])
+            .push(vec![Bytes(&[0xaa; 2])])
+            .build();
+
+        let types = SolType::parse("(uint8,string,uint16[],bytes[])")?;
+        let values = match SolValue::decode(&types, &buf)? {
+            SolValue::Tuple(values) => values,
+            _ => unreachable!(),
+        };
+
+        let mut uint8 = [0u8; 32];
+        uint8[31] = 0xff;
+        assert_eq!(values[0], SolValue::Uint(uint8));
+        assert_eq!(values[1], SolValue::String("random string"));
+        match &values[2] {
+            SolValue::Array(values) => assert_eq!(values.len(), 2),
+            _ => panic!("expected an array"),
+        }
+        assert_eq!(
+            values[3],
+            SolValue::Array(vec![SolValue::Bytes(&[0xaa; 2])])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_decimal_test() {
+        let mut word = [0u8; 32];
+        assert_eq!(to_decimal(&word, false), "0");
+
+        word[30] = 0x01;
+        word[31] = 0x2c;
+        assert_eq!(to_decimal(&word, true), "300");
+
+        let word = [0xffu8; 32];
+        assert_eq!(to_decimal(&word, true), "-1");
+        assert_eq!(
+            to_decimal(&word, false),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+
+        let mut word = [0u8; 32];
+        word[0] = 0x80;
+        assert_eq!(
+            to_decimal(&word, true),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+    }
+
+    #[test]
+    fn sol_value_static_tuple_test() -> Result<()> {
+        // Static tuples and fixed size arrays are encoded in place
+        let mut buf = vec![0u8; 32 * 4];
+        buf[31] = 1;
+        buf[63] = 2;
+        buf[95] = 3;
+        buf[127] = 1;
+
+        let types = [SolType::parse("(uint8,uint8[2])")?, SolType::Bool];
+        let values = SolValue::decode_tuple(&types, &buf)?;
+
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1], SolValue::Bool(true));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    address::Address,
+    bytes::Bytes,
+    decode::{
+        decode_at,
+        eof,
+        follow,
+        read_usize,
+        word,
+        Decode,
+    },
+    encode::Encode,
+    int::stable::{
+        Int256,
+        Uint256,
+    },
+    Error,
+    Result,
+};
+
+/// Lazy, random-access view over an ABI encoded buffer
+///
+/// Nothing is decoded or copied up front. Head/tail offsets are only resolved while
+/// navigating, and `bytes` and `string` values are borrowed straight from the buffer.
+/// A view always points at the head word of a value inside the tuple or array that
+/// contains it, which is what offsets are relative to.
+///
+/// ```rust
+/// # use solid_core::{builder::Builder, view::AbiView};
+/// # fn main() -> solid_core::Result<()> {
+/// let buf = Builder::new()
+///     .push(1u8)
+///     .push(vec![1u64, 2, 3])
+///     .build();
+///
+/// let view = AbiView::new(&buf);
+/// assert_eq!(view.tuple(1)?.array()?.get(2)?.decode::<u64>()?, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AbiView<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> AbiView<'a> {
+    /// Create a view over an encoded tuple, such as a function's return data
+    pub fn new(buf: &'a [u8]) -> Self {
+        AbiView { buf, pos: 0 }
+    }
+
+    /// The head word of the current value
+    pub fn word(&self) -> Result<&'a [u8]> {
+        word(self.buf, self.pos / 32)
+    }
+
+    /// Treat the current value as a tuple whose head starts here and get its `index`th member
+    ///
+    /// Every member before `index` must take up a single head word, which holds for value
+    /// types and dynamic members. Use `tuple_with_head_lens` when a static tuple or fixed-size
+    /// array precedes the member.
+    pub fn tuple(&self, index: usize) -> Result<AbiView<'a>> {
+        let buf = self
+            .buf
+            .get(self.pos..)
+            .ok_or_else(|| eof(self.buf, self.pos))?;
+        word(buf, index)?;
+        Ok(AbiView {
+            buf,
+            pos: index * 32,
+        })
+    }
+
+    /// Treat the current value as a tuple whose members take up `head_lens` bytes of the head
+    /// and get its `index`th member
+    ///
+    /// The head length of a member of type `T` is `T::head_len()`, e.g.
+    /// `view.tuple_with_head_lens(&[<(u64, u64)>::head_len() as usize, 32], 1)`.
+    pub fn tuple_with_head_lens(&self, head_lens: &[usize], index: usize) -> Result<AbiView<'a>> {
+        let head_len = *head_lens.get(index).ok_or_else(|| {
+            Error::Message(format!(
+                "index {} out of bounds for tuple of {} members",
+                index,
+                head_lens.len()
+            ))
+        })?;
+        let pos = head_lens[..index].iter().sum::<usize>();
+
+        let buf = self
+            .buf
+            .get(self.pos..)
+            .ok_or_else(|| eof(self.buf, self.pos))?;
+        buf.get(pos..pos + head_len)
+            .ok_or_else(|| eof(buf, pos + head_len))?;
+
+        Ok(AbiView { buf, pos })
+    }
+
+    /// Follow the offset stored in the head word of a dynamic value
+    ///
+    /// Used to step into dynamic tuples, e.g. `view.tuple(0)?.follow()?.tuple(1)?`.
+    pub fn follow(&self) -> Result<AbiView<'a>> {
+        Ok(AbiView {
+            buf: follow(self.buf, self.pos / 32)?,
+            pos: 0,
+        })
+    }
+
+    /// Follow the offset of a dynamic array whose elements take up a single head word and
+    /// return a view over its elements
+    ///
+    /// This covers every value type as well as dynamic elements, whose head is an offset. Use
+    /// `array_of` for arrays of static tuples or fixed-size arrays.
+    pub fn array(&self) -> Result<ArrayView<'a>> {
+        self.array_with_head_len(32)
+    }
+
+    /// Follow the offset of a dynamic array `T[]` and return a view over its elements, which
+    /// are `T::head_len()` bytes apart
+    pub fn array_of<T: Encode>(&self) -> Result<ArrayView<'a>> {
+        self.array_with_head_len(T::head_len() as usize)
+    }
+
+    fn array_with_head_len(&self, head_len: usize) -> Result<ArrayView<'a>> {
+        let buf = follow(self.buf, self.pos / 32)?;
+        let len = read_usize(buf)?;
+        let buf = &buf[32..];
+
+        // Every element has a head, so the length can be validated up front.
+        if len > buf.len() / head_len.max(1) {
+            return Err(eof(buf, len.saturating_mul(head_len)));
+        }
+
+        Ok(ArrayView { buf, len, head_len })
+    }
+
+    /// Decode the current value using `T`'s `Decode` implementation
+    pub fn decode<T>(&self) -> Result<T>
+    where
+        T: Decode<'a> + Encode,
+    {
+        decode_at(self.buf, self.pos)
+    }
+
+    pub fn as_uint(&self) -> Result<Uint256> {
+        self.decode()
+    }
+
+    pub fn as_int(&self) -> Result<Int256> {
+        self.decode()
+    }
+
+    pub fn as_bool(&self) -> Result<bool> {
+        self.decode()
+    }
+
+    pub fn as_address(&self) -> Result<Address> {
+        self.decode()
+    }
+
+    /// Borrow the contents of a `bytes` value without copying
+    pub fn as_bytes(&self) -> Result<&'a [u8]> {
+        Ok(self.decode::<Bytes<'a>>()?.0)
+    }
+
+    /// Borrow the contents of a `string` value without copying
+    pub fn as_str(&self) -> Result<&'a str> {
+        self.decode()
+    }
+}
+
+/// View over the elements of an encoded dynamic array
+///
+/// Elements are located by the number of bytes each takes up in the head: a single word when
+/// created by `AbiView::array`, and `T::head_len()` when created by `AbiView::array_of::<T>`,
+/// which is what static tuples and fixed-size arrays stored in place require.
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayView<'a> {
+    buf: &'a [u8],
+    len: usize,
+    head_len: usize,
+}
+
+impl<'a> ArrayView<'a> {
+    /// Number of elements in the array
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get a view of the element at `index`
+    pub fn get(&self, index: usize) -> Result<AbiView<'a>> {
+        if index >= self.len {
+            return Err(Error::Message(format!(
+                "index {} out of bounds for array of length {}",
+                index, self.len
+            )));
+        }
+
+        Ok(AbiView {
+            buf: self.buf,
+            pos: index * self.head_len,
+        })
+    }
+
+    /// Iterate over views of every element
+    pub fn iter(&self) -> impl Iterator<Item = AbiView<'a>> {
+        let buf = self.buf;
+        let head_len = self.head_len;
+        (0..self.len).map(move |index| AbiView {
+            buf,
+            pos: index * head_len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::Builder;
+
+    #[test]
+    fn view_navigation_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(7u8)
+            .push(vec![10u64, 20, 30])
+            .push("random string")
+            .push(vec![Bytes(&[0xaa; 4]), Bytes(&[0xbb; 40])])
+            .build();
+
+        let view = AbiView::new(&buf);
+
+        assert_eq!(view.tuple(0)?.decode::<u8>()?, 7);
+
+        let array = view.tuple(1)?.array()?;
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.get(2)?.decode::<u64>()?, 30);
+        assert_eq!(array.get(1)?.as_uint()?.0[31], 20);
+        assert!(array.get(3).is_err());
+
+        let string = view.tuple(2)?.as_str()?;
+        assert_eq!(string, "random string");
+        // Borrowed straight out of the buffer
+        let offset = string.as_ptr() as usize - buf.as_ptr() as usize;
+        assert_eq!(&buf[offset..offset + string.len()], string.as_bytes());
+
+        let bytes = view.tuple(3)?.array()?;
+        assert_eq!(bytes.get(0)?.as_bytes()?, &[0xaa; 4][..]);
+        assert_eq!(bytes.get(1)?.as_bytes()?, &[0xbb; 40][..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_nested_tuple_test() -> Result<()> {
+        let buf = Builder::new().push((1u8, "inner")).push(2u16).build();
+
+        let view = AbiView::new(&buf);
+        let inner = view.tuple(0)?.follow()?;
+
+        assert_eq!(inner.tuple(0)?.decode::<u8>()?, 1);
+        assert_eq!(inner.tuple(1)?.as_str()?, "inner");
+        assert_eq!(view.tuple(1)?.decode::<u16>()?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_static_elements_test() -> Result<()> {
+        let buf = Builder::new()
+            .push(vec![(1u64, 2u64), (3, 4), (5, 6)])
+            .build();
+
+        let points = AbiView::new(&buf).tuple(0)?.array_of::<(u64, u64)>()?;
+        assert_eq!(points.len(), 3);
+        assert_eq!(points.get(1)?.decode::<(u64, u64)>()?, (3, 4));
+        assert_eq!(points.get(2)?.tuple(1)?.decode::<u64>()?, 6);
+        assert_eq!(
+            points
+                .iter()
+                .map(|point| point.tuple(0)?.decode::<u64>())
+                .collect::<Result<Vec<_>>>()?,
+            vec![1, 3, 5]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_static_tuple_member_test() -> Result<()> {
+        let buf = Builder::new()
+            .push((1u64, 2u64))
+            .push(3u64)
+            .push("after")
+            .build();
+
+        let view = AbiView::new(&buf);
+        let head_lens = [<(u64, u64)>::head_len() as usize, 32, 32];
+
+        assert_eq!(
+            view.tuple_with_head_lens(&head_lens, 0)?
+                .decode::<(u64, u64)>()?,
+            (1, 2)
+        );
+        assert_eq!(
+            view.tuple_with_head_lens(&head_lens, 1)?.decode::<u64>()?,
+            3
+        );
+        assert_eq!(view.tuple_with_head_lens(&head_lens, 2)?.as_str()?, "after");
+        assert!(view.tuple_with_head_lens(&head_lens, 3).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn view_truncated_test() {
+        let buf = Builder::new().push(vec![1u64, 2, 3]).build();
+
+        assert!(AbiView::new(&buf[..64]).tuple(0).unwrap().array().is_err());
+        assert!(AbiView::new(&buf).tuple(10).is_err());
+    }
+}
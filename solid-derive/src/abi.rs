@@ -0,0 +1,112 @@
+use super::{
+    bound::with_bounds,
+    field::fields,
+    StructAttrs,
+};
+use proc_macro2::TokenStream;
+use syn::{
+    DeriveInput,
+    Error,
+    Result,
+};
+
+pub(super) fn impl_abi(ast: &DeriveInput) -> Result<TokenStream> {
+    let ident = &ast.ident;
+
+    let attrs = StructAttrs::new(ast)?;
+    if attrs.transparent {
+        return Err(Error::new(
+            ident.span(),
+            "`Abi` cannot be derived for transparent structs, which are types rather than entries",
+        ));
+    }
+
+    let fields = fields(ast, "Abi")?
+        .into_iter()
+        .filter(|field| !field.skip)
+        .collect::<Vec<_>>();
+
+    if !attrs.event {
+        if let Some(indexed) = fields.iter().find_map(|field| field.indexed.as_ref()) {
+            return Err(Error::new(
+                indexed.span(),
+                "`indexed` can only be used on the fields of events",
+            ));
+        }
+    }
+
+    let generics = with_bounds(&ast.generics, &fields, attrs.bound.as_deref(), |field| {
+        if field.sol_ty.is_none() {
+            vec![quote! { solid::into_type::IntoType }]
+        } else {
+            Vec::new()
+        }
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let ty = if attrs.event {
+        quote! { Event }
+    } else if attrs.error {
+        quote! { Error }
+    } else if attrs.constructor {
+        quote! { Constructor }
+    } else {
+        quote! { Function }
+    };
+
+    let name = if attrs.constructor {
+        quote! { None }
+    } else {
+        let name = &attrs.name;
+        quote! { Some(#name.to_string()) }
+    };
+
+    let state_mutability = if attrs.event || attrs.error {
+        quote! { None }
+    } else {
+        let mutability = match attrs
+            .mutability
+            .as_ref()
+            .map(ToString::to_string)
+            .as_deref()
+        {
+            Some("view") => quote! { View },
+            Some("pure") => quote! { Pure },
+            Some("payable") => quote! { Payable },
+            _ => quote! { NonPayable },
+        };
+        quote! { Some(solid::abi::StateMutability::#mutability) }
+    };
+
+    let anonymous = if attrs.event {
+        let anonymous = attrs.anonymous;
+        quote! { Some(#anonymous) }
+    } else {
+        quote! { None }
+    };
+
+    let param = fields.iter().map(|field| {
+        let param = field.param();
+        if attrs.event {
+            let indexed = field.indexed.is_some();
+            quote! { #param.indexed(#indexed) }
+        } else {
+            param
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics solid::abi::Abi for #ident #ty_generics #where_clause {
+            fn abi() -> solid::abi::Entry {
+                solid::abi::Entry {
+                    ty: solid::abi::EntryType::#ty,
+                    name: #name,
+                    inputs: vec![#(#param),*],
+                    outputs: Vec::new(),
+                    state_mutability: #state_mutability,
+                    anonymous: #anonymous,
+                }
+            }
+        }
+    })
+}
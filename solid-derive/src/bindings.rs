@@ -0,0 +1,884 @@
+use super::interface::{
+    pascal_case,
+    snake_case,
+};
+use proc_macro2::{
+    Literal,
+    Span,
+    TokenStream,
+};
+use solid_core::{
+    selector::Selector,
+    value::SolType,
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+};
+use syn::{
+    Attribute,
+    Error,
+    Ident,
+    LitStr,
+    Result,
+};
+
+/// Type of a parameter, field or constant as declared in Solidity
+pub(super) enum Ty {
+    /// Elementary type such as `uint256` or `string`
+    Elementary(SolType, Span),
+    /// Struct, enum, user defined value type or contract declared in the same bindings
+    Named(Ident),
+    /// `T[]`, or `T[N]` if the length is given
+    Array(Box<Ty>, Option<usize>, Span),
+}
+
+/// Parameter of a function, event or error, or field of a struct
+pub(super) struct Param {
+    /// `None` for unnamed parameters
+    pub(super) name: Option<Ident>,
+    pub(super) ty: Ty,
+    /// Whether an event parameter is a topic
+    pub(super) indexed: bool,
+}
+
+pub(super) struct Function {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) inputs: Vec<Param>,
+    pub(super) outputs: Vec<Param>,
+}
+
+pub(super) struct Event {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) params: Vec<Param>,
+    pub(super) anonymous: bool,
+}
+
+pub(super) struct CustomError {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) params: Vec<Param>,
+}
+
+pub(super) struct Struct {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) fields: Vec<Param>,
+}
+
+pub(super) struct Enum {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) variants: Vec<Ident>,
+}
+
+/// User defined value type, `type Price is uint128;`
+pub(super) struct ValueType {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) ty: Ty,
+}
+
+pub(super) struct Constant {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    pub(super) ty: Ty,
+    pub(super) value: Value,
+}
+
+/// Literal value of a constant
+pub(super) enum Value {
+    Bool(bool, Span),
+    String(LitStr),
+    /// `hex"..."`
+    Hex(Vec<u8>, Span),
+    /// Integer with its big endian magnitude, and its number of digits if written in hex
+    Number {
+        negative: bool,
+        magnitude: Vec<u8>,
+        hex_digits: Option<usize>,
+        span: Span,
+    },
+}
+
+/// Interface, contract or library
+pub(super) struct Contract {
+    pub(super) docs: Vec<Attribute>,
+    pub(super) name: Ident,
+    /// Contracts inherited from, whose functions are included if they are declared in the same
+    /// bindings
+    pub(super) bases: Vec<Ident>,
+    pub(super) items: Vec<Item>,
+}
+
+pub(super) enum Item {
+    Contract(Contract),
+    Function(Function),
+    Constructor(Vec<Param>),
+    Event(Event),
+    Error(CustomError),
+    Struct(Struct),
+    Enum(Enum),
+    ValueType(ValueType),
+    Constant(Constant),
+}
+
+/// What a type name refers to
+enum Definition<'a> {
+    Struct(&'a Struct),
+    Enum,
+    ValueType(&'a Ty),
+    Contract(&'a Contract),
+}
+
+/// Generates the Rust bindings of Solidity declarations
+///
+/// Every type is generated in the calling scope, regardless of the contract declaring it. Each
+/// contract becomes a unit struct whose associated functions encode the calldata of its
+/// functions, like the files written by `cargo-solid`.
+pub(super) fn expand(items: &[Item]) -> Result<TokenStream> {
+    let mut bindings = Bindings {
+        definitions: HashMap::new(),
+        declared: RefCell::new(HashMap::new()),
+    };
+
+    for item in items {
+        bindings.define(item)?;
+        if let Item::Contract(contract) = item {
+            for item in &contract.items {
+                bindings.define(item)?;
+            }
+        }
+    }
+
+    let mut tokens = TokenStream::new();
+    for item in items {
+        tokens.extend(match item {
+            Item::Contract(contract) => bindings.contract(contract)?,
+            Item::Constant(constant) => {
+                let docs = &constant.docs;
+                let ident = &constant.name;
+                let (ty, value) = bindings.constant(constant)?;
+                quote! {
+                    #(#docs)*
+                    #[allow(non_upper_case_globals)]
+                    pub const #ident: #ty = #value;
+                }
+            }
+            item => bindings.ty_item(item)?,
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Bindings<'a> {
+    definitions: HashMap<String, Definition<'a>>,
+    /// Generated structs by name, along with the parameters they were generated from
+    declared: RefCell<HashMap<String, String>>,
+}
+
+impl<'a> Bindings<'a> {
+    fn define(&mut self, item: &'a Item) -> Result<()> {
+        let (name, definition) = match item {
+            Item::Contract(contract) => (&contract.name, Definition::Contract(contract)),
+            Item::Struct(definition) => (&definition.name, Definition::Struct(definition)),
+            Item::Enum(definition) => (&definition.name, Definition::Enum),
+            Item::ValueType(definition) => {
+                (&definition.name, Definition::ValueType(&definition.ty))
+            }
+            _ => return Ok(()),
+        };
+
+        // Contracts built together often share struct definitions, which are checked for
+        // conflicts when they are generated
+        let existing = self.definitions.get(&name.to_string());
+        if let (Some(Definition::Struct(_)), Definition::Struct(_)) = (existing, &definition) {
+            return Ok(());
+        }
+
+        if self
+            .definitions
+            .insert(name.to_string(), definition)
+            .is_some()
+        {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "`{}` is defined more than once, the bindings of every contract share a single namespace",
+                    name
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn lookup(&self, name: &Ident) -> Result<&Definition<'a>> {
+        self.definitions.get(&name.to_string()).ok_or_else(|| {
+            Error::new(
+                name.span(),
+                format!(
+                    "unknown type `{}`, structs, enums and user defined value types must be declared in the same `sol!` invocation",
+                    name
+                ),
+            )
+        })
+    }
+
+    /// Canonical ABI type, as used in signatures
+    fn abi_type(&self, ty: &Ty, depth: usize) -> Result<String> {
+        match ty {
+            Ty::Elementary(SolType::Fixed { .. }, span) => {
+                Err(Error::new(*span, "fixed point types are not supported"))
+            }
+            Ty::Elementary(ty, _) => Ok(ty.to_string()),
+            Ty::Array(ty, Some(len), _) => Ok(format!("{}[{}]", self.abi_type(ty, depth)?, len)),
+            Ty::Array(ty, None, _) => Ok(format!("{}[]", self.abi_type(ty, depth)?)),
+            Ty::Named(name) => match self.lookup(name)? {
+                Definition::Struct(definition) => {
+                    if depth > 32 {
+                        return Err(Error::new(
+                            name.span(),
+                            "recursive structs are not supported",
+                        ));
+                    }
+                    let fields = definition
+                        .fields
+                        .iter()
+                        .map(|field| self.abi_type(&field.ty, depth + 1))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(format!("({})", fields.join(",")))
+                }
+                Definition::Enum => Ok("uint8".to_string()),
+                Definition::ValueType(ty) => self.abi_type(ty, depth),
+                Definition::Contract(_) => Ok("address".to_string()),
+            },
+        }
+    }
+
+    /// Whether the Rust type borrows from the buffer it is decoded from
+    fn borrows(&self, ty: &Ty, depth: usize) -> bool {
+        match ty {
+            Ty::Elementary(SolType::String, _) | Ty::Elementary(SolType::Bytes, _) => true,
+            Ty::Elementary(..) => false,
+            Ty::Array(ty, _, _) => self.borrows(ty, depth),
+            Ty::Named(name) => match self.definitions.get(&name.to_string()) {
+                Some(Definition::Struct(definition)) if depth <= 32 => definition
+                    .fields
+                    .iter()
+                    .any(|field| self.borrows(&field.ty, depth + 1)),
+                _ => false,
+            },
+        }
+    }
+
+    /// Rust type of `ty`, using `lifetime` for borrowed values
+    fn rust_type(&self, ty: &Ty, lifetime: &TokenStream) -> Result<TokenStream> {
+        Ok(match ty {
+            Ty::Elementary(ty, span) => match ty {
+                SolType::Address => quote! { solid::Address },
+                SolType::Bool => quote! { bool },
+                SolType::Function => quote! { solid::Function },
+                SolType::String => quote! { &#lifetime str },
+                SolType::Bytes => quote! { solid::Bytes<#lifetime> },
+                SolType::Int(bits) | SolType::Uint(bits) => {
+                    let signed = matches!(ty, SolType::Int(_));
+                    match bits {
+                        8 | 16 | 32 | 64 | 128 => {
+                            let ident = Ident::new(
+                                &format!("{}{}", if signed { "i" } else { "u" }, bits),
+                                *span,
+                            );
+                            quote! { #ident }
+                        }
+                        _ => {
+                            let ident = Ident::new(
+                                &format!("{}{}", if signed { "Int" } else { "Uint" }, bits),
+                                *span,
+                            );
+                            quote! { solid::int::#ident }
+                        }
+                    }
+                }
+                SolType::FixedBytes(len) => {
+                    let ident = Ident::new(&format!("Bytes{}", len), *span);
+                    quote! { solid::bytesfix::#ident }
+                }
+                SolType::Fixed { .. } => {
+                    return Err(Error::new(*span, "fixed point types are not supported"))
+                }
+                SolType::Array(_) | SolType::FixedArray(..) | SolType::Tuple(_) => {
+                    return Err(Error::new(*span, "expected an elementary type"))
+                }
+            },
+            Ty::Array(ty, None, _) => {
+                let ty = self.rust_type(ty, lifetime)?;
+                quote! { Vec<#ty> }
+            }
+            Ty::Array(ty, Some(len), _) => {
+                let ty = self.rust_type(ty, lifetime)?;
+                let len = Literal::usize_unsuffixed(*len);
+                quote! { [#ty; #len] }
+            }
+            Ty::Named(name) => match self.lookup(name)? {
+                Definition::Struct(_) if self.borrows(ty, 0) => quote! { #name<#lifetime> },
+                Definition::Contract(_) => quote! { solid::Address },
+                _ => quote! { #name },
+            },
+        })
+    }
+
+    /// Named fields of a generated struct, renamed back to their Solidity names where needed
+    fn fields(&self, params: &[Param], lifetime: &TokenStream) -> Result<TokenStream> {
+        let mut fields = TokenStream::new();
+        for (index, param) in params.iter().enumerate() {
+            let ident = rust_ident(param.name.as_ref(), "field", index);
+            let ty = self.rust_type(&param.ty, lifetime)?;
+            let mut attributes = Vec::new();
+            if let Some(name) = &param.name {
+                if ident != *name {
+                    let name = name.to_string();
+                    attributes.push(quote! { rename = #name });
+                }
+            }
+            if param.indexed {
+                attributes.push(quote! { indexed });
+            }
+            let attributes = if attributes.is_empty() {
+                None
+            } else {
+                Some(quote! { #[solid(#(#attributes),*)] })
+            };
+
+            fields.extend(quote! {
+                #attributes
+                pub #ident: #ty,
+            });
+        }
+        Ok(fields)
+    }
+
+    /// Generics of a struct with `params` as fields
+    fn generics(&self, params: &[Param]) -> Option<TokenStream> {
+        if params.iter().any(|param| self.borrows(&param.ty, 0)) {
+            Some(quote! { <'a> })
+        } else {
+            None
+        }
+    }
+
+    fn signature(&self, name: &Ident, params: &[Param]) -> Result<String> {
+        let types = params
+            .iter()
+            .map(|param| self.abi_type(&param.ty, 0))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("{}({})", name, types.join(",")))
+    }
+
+    /// Whether the struct `name` with `params` as fields still needs to be generated
+    ///
+    /// Events, errors and structs declared by several contracts are generated once, as long as
+    /// every declaration has the same fields.
+    fn declare(&self, name: &Ident, params: &[Param]) -> Result<bool> {
+        let mut fields = Vec::new();
+        for param in params {
+            fields.push(format!(
+                "{} {}{}",
+                self.abi_type(&param.ty, 0)?,
+                if param.indexed { "indexed " } else { "" },
+                param
+                    .name
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ));
+        }
+        let fields = fields.join(",");
+
+        let mut declared = self.declared.borrow_mut();
+        match declared.get(&name.to_string()) {
+            Some(existing) if *existing == fields => Ok(false),
+            Some(_) => Err(Error::new(
+                name.span(),
+                format!("conflicting declarations of `{}`", name),
+            )),
+            None => {
+                declared.insert(name.to_string(), fields);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Structs, enums, user defined value types, events and errors
+    fn ty_item(&self, item: &Item) -> Result<TokenStream> {
+        let lifetime = quote! { 'a };
+
+        Ok(match item {
+            Item::Struct(definition) if !self.declare(&definition.name, &definition.fields)? => {
+                TokenStream::new()
+            }
+            Item::Struct(definition) => {
+                let docs = &definition.docs;
+                let ident = &definition.name;
+                let generics = self.generics(&definition.fields);
+                let fields = self.fields(&definition.fields, &lifetime)?;
+                quote! {
+                    #(#docs)*
+                    #[derive(solid::Encode, solid::Decode)]
+                    pub struct #ident #generics {
+                        #fields
+                    }
+                }
+            }
+            Item::Enum(definition) => {
+                let docs = &definition.docs;
+                let ident = &definition.name;
+                let variants = &definition.variants;
+                quote! {
+                    #(#docs)*
+                    #[derive(solid::Encode, solid::Decode)]
+                    #[allow(non_camel_case_types)]
+                    pub enum #ident {
+                        #(#variants,)*
+                    }
+                }
+            }
+            Item::ValueType(definition) => {
+                let docs = &definition.docs;
+                let ident = &definition.name;
+                if self.borrows(&definition.ty, 0) {
+                    return Err(Error::new(
+                        ident.span(),
+                        "user defined value types must wrap an elementary value type",
+                    ));
+                }
+                let ty = self.rust_type(&definition.ty, &lifetime)?;
+                quote! {
+                    #(#docs)*
+                    #[derive(solid::Encode, solid::Decode)]
+                    #[solid(transparent)]
+                    pub struct #ident(pub #ty);
+                }
+            }
+            Item::Event(event) if !self.declare(&event.name, &event.params)? => TokenStream::new(),
+            Item::Event(event) => {
+                let docs = &event.docs;
+                let ident = &event.name;
+                let generics = self.generics(&event.params);
+                let fields = self.fields(&event.params, &lifetime)?;
+                let signature = self.signature(ident, &event.params)?;
+                let attributes = if event.anonymous {
+                    quote! { #[solid(event, anonymous)] }
+                } else {
+                    quote! { #[solid(event)] }
+                };
+                quote! {
+                    #(#docs)*
+                    #[derive(solid::Abi)]
+                    #attributes
+                    pub struct #ident #generics {
+                        #fields
+                    }
+
+                    impl #generics #ident #generics {
+                        /// Canonical signature of the event, whose hash is its first topic
+                        pub const SIGNATURE: &'static str = #signature;
+                    }
+                }
+            }
+            Item::Error(error) if !self.declare(&error.name, &error.params)? => TokenStream::new(),
+            Item::Error(error) => {
+                let docs = &error.docs;
+                let ident = &error.name;
+                let generics = self.generics(&error.params);
+                let fields = self.fields(&error.params, &lifetime)?;
+                quote! {
+                    #(#docs)*
+                    #[derive(solid::Encode, solid::Decode, solid::Abi)]
+                    #[solid(error)]
+                    pub struct #ident #generics {
+                        #fields
+                    }
+                }
+            }
+            Item::Contract(_) | Item::Function(_) | Item::Constructor(_) | Item::Constant(_) => {
+                TokenStream::new()
+            }
+        })
+    }
+
+    /// Functions of `contract` and of the contracts it inherits from, with overridden
+    /// functions listed once
+    fn functions(&self, contract: &'a Contract, depth: usize) -> Result<Vec<&'a Function>> {
+        let mut functions = Vec::new();
+
+        if depth <= 32 {
+            for base in &contract.bases {
+                if let Some(Definition::Contract(base)) = self.definitions.get(&base.to_string()) {
+                    functions.extend(self.functions(base, depth + 1)?);
+                }
+            }
+        }
+
+        for item in &contract.items {
+            if let Item::Function(function) = item {
+                functions.push(function);
+            }
+        }
+
+        let mut signatures = Vec::new();
+        let mut unique = Vec::<&Function>::new();
+        for function in functions {
+            let signature = self.signature(&function.name, &function.inputs)?;
+            match signatures
+                .iter()
+                .position(|existing| *existing == signature)
+            {
+                Some(index) => unique[index] = function,
+                None => {
+                    signatures.push(signature);
+                    unique.push(function);
+                }
+            }
+        }
+
+        Ok(unique)
+    }
+
+    fn contract(&self, contract: &'a Contract) -> Result<TokenStream> {
+        let ident = &contract.name;
+        let docs = &contract.docs;
+        let elided = quote! { '_ };
+
+        let mut types = TokenStream::new();
+        let mut consts = TokenStream::new();
+        let mut methods = TokenStream::new();
+
+        for item in &contract.items {
+            match item {
+                Item::Constant(constant) => {
+                    let docs = &constant.docs;
+                    let ident = &constant.name;
+                    let (ty, value) = self.constant(constant)?;
+                    consts.extend(quote! {
+                        #(#docs)*
+                        #[allow(non_upper_case_globals)]
+                        pub const #ident: #ty = #value;
+                    });
+                }
+                Item::Constructor(inputs) => {
+                    let (arg, ty) = self.args(inputs, &elided)?;
+                    methods.extend(quote! {
+                        /// Encodes the arguments of the constructor, to be appended to the
+                        /// contract's bytecode
+                        #[allow(dead_code)]
+                        pub fn new(#(#arg: #ty),*) -> Vec<u8> {
+                            solid::Builder::new()
+                                #(.push(#arg))*
+                                .build()
+                        }
+                    });
+                }
+                item => types.extend(self.ty_item(item)?),
+            }
+        }
+
+        let functions = self.functions(contract, 0)?;
+        let names = functions
+            .iter()
+            .map(|function| rust_ident(Some(&function.name), "function", 0).to_string())
+            .collect::<Vec<_>>();
+
+        for (function, name) in functions.iter().zip(&names) {
+            let signature = self.signature(&function.name, &function.inputs)?;
+            let selector = Selector::from_signature(&signature);
+
+            // Overloads share a Solidity name, so each is suffixed with its parameter types
+            let mut name = name.clone();
+            if names.iter().filter(|other| **other == name).count() > 1 {
+                for param in &function.inputs {
+                    name.push('_');
+                    name.push_str(&type_suffix(&param.ty));
+                }
+            }
+            let method = Ident::new(&name, function.name.span());
+
+            let docs = &function.docs;
+            let separator = if docs.is_empty() {
+                None
+            } else {
+                Some(quote! { #[doc = ""] })
+            };
+            let doc = format!("Encodes a call to `{}`", signature);
+            let (arg, ty) = self.args(&function.inputs, &elided)?;
+
+            methods.extend(quote! {
+                #(#docs)*
+                #separator
+                #[doc = #doc]
+                #[allow(dead_code)]
+                pub fn #method(#(#arg: #ty),*) -> Vec<u8> {
+                    solid::Builder::new()
+                        .selector([#(#selector),*])
+                        #(.push(#arg))*
+                        .build()
+                }
+            });
+
+            let own = contract.items.iter().any(|item| match item {
+                Item::Function(own) => std::ptr::eq(own, *function),
+                _ => false,
+            });
+            let named = function.outputs.iter().all(|output| output.name.is_some());
+            let output = Ident::new(
+                &format!("{}Output", pascal_case(&name)),
+                function.name.span(),
+            );
+            if own
+                && named
+                && !function.outputs.is_empty()
+                && self.declare(&output, &function.outputs)?
+            {
+                let doc = format!("Values returned by `{}`", signature);
+                let generics = self.generics(&function.outputs);
+                let fields = self.fields(&function.outputs, &quote! { 'a })?;
+                types.extend(quote! {
+                    #[doc = #doc]
+                    #[derive(solid::Decode)]
+                    pub struct #output #generics {
+                        #fields
+                    }
+                });
+            }
+        }
+
+        Ok(quote! {
+            #types
+
+            #(#docs)*
+            pub struct #ident;
+
+            impl #ident {
+                #consts
+                #methods
+            }
+        })
+    }
+
+    /// Argument names and types of an encoding function
+    fn args(
+        &self,
+        params: &[Param],
+        lifetime: &TokenStream,
+    ) -> Result<(Vec<Ident>, Vec<TokenStream>)> {
+        let mut args = Vec::new();
+        let mut types = Vec::new();
+        for (index, param) in params.iter().enumerate() {
+            args.push(rust_ident(param.name.as_ref(), "arg", index));
+            types.push(self.rust_type(&param.ty, lifetime)?);
+        }
+        Ok((args, types))
+    }
+
+    /// Rust type and value of a constant
+    fn constant(&self, constant: &Constant) -> Result<(TokenStream, TokenStream)> {
+        let invalid = |span: Span| {
+            Error::new(
+                span,
+                format!("invalid value for constant `{}`", constant.name),
+            )
+        };
+
+        let ty = match &constant.ty {
+            Ty::Elementary(ty, _) => ty,
+            Ty::Array(_, _, span) => {
+                return Err(Error::new(*span, "array constants are not supported"))
+            }
+            Ty::Named(name) => {
+                return Err(Error::new(
+                    name.span(),
+                    "only constants of elementary types are supported",
+                ))
+            }
+        };
+        let rust_type = self.rust_type(&constant.ty, &quote! { 'static })?;
+
+        let value = match (ty, &constant.value) {
+            (SolType::Bool, Value::Bool(value, _)) => quote! { #value },
+            (SolType::String, Value::String(value)) => quote! { #value },
+            (SolType::Bytes, Value::Hex(bytes, _)) => quote! { solid::Bytes(&[#(#bytes),*]) },
+            (SolType::Bytes, Value::String(value)) => {
+                let bytes = value.value().into_bytes();
+                quote! { solid::Bytes(&[#(#bytes),*]) }
+            }
+            (SolType::FixedBytes(len), value) => {
+                let bytes = match value {
+                    Value::Hex(bytes, _) if bytes.len() == *len => bytes.clone(),
+                    Value::String(value) if value.value().len() <= *len => {
+                        let mut bytes = value.value().into_bytes();
+                        bytes.resize(*len, 0);
+                        bytes
+                    }
+                    Value::Number {
+                        negative: false,
+                        magnitude,
+                        hex_digits: Some(digits),
+                        ..
+                    } if *digits == len * 2 => {
+                        let mut bytes = vec![0u8; *len];
+                        bytes[len - magnitude.len()..].copy_from_slice(magnitude);
+                        bytes
+                    }
+                    value => return Err(invalid(value.span())),
+                };
+                quote! { #rust_type([#(#bytes),*]) }
+            }
+            (
+                SolType::Address,
+                Value::Number {
+                    negative: false,
+                    magnitude,
+                    hex_digits: Some(40),
+                    ..
+                },
+            ) => {
+                let mut bytes = [0u8; 32];
+                bytes[32 - magnitude.len()..].copy_from_slice(magnitude);
+                quote! { solid::Address([#(#bytes),*]) }
+            }
+            (
+                SolType::Int(bits),
+                Value::Number {
+                    negative,
+                    magnitude,
+                    span,
+                    ..
+                },
+            )
+            | (
+                SolType::Uint(bits),
+                Value::Number {
+                    negative,
+                    magnitude,
+                    span,
+                    ..
+                },
+            ) => {
+                let signed = matches!(ty, SolType::Int(_));
+                let bytes = integer(*negative, magnitude, signed, *bits).ok_or_else(|| {
+                    Error::new(
+                        *span,
+                        format!(
+                            "value of constant `{}` does not fit in `{}`",
+                            constant.name, ty
+                        ),
+                    )
+                })?;
+                if *bits <= 128 && bits.is_power_of_two() && *bits >= 8 {
+                    let mut word = if signed && bytes[0] & 0x80 != 0 {
+                        [0xff; 16]
+                    } else {
+                        [0; 16]
+                    };
+                    word[16 - bytes.len()..].copy_from_slice(&bytes);
+                    if signed {
+                        let value = i128::from_be_bytes(word);
+                        let literal = Literal::u128_unsuffixed(value.wrapping_abs() as u128);
+                        if value < 0 {
+                            quote! { -#literal }
+                        } else {
+                            quote! { #literal }
+                        }
+                    } else {
+                        let literal = Literal::u128_unsuffixed(u128::from_be_bytes(word));
+                        quote! { #literal }
+                    }
+                } else {
+                    quote! { #rust_type([#(#bytes),*]) }
+                }
+            }
+            (_, value) => return Err(invalid(value.span())),
+        };
+
+        Ok((rust_type, value))
+    }
+}
+
+impl Value {
+    fn span(&self) -> Span {
+        match self {
+            Value::Bool(_, span) | Value::Hex(_, span) | Value::Number { span, .. } => *span,
+            Value::String(value) => value.span(),
+        }
+    }
+}
+
+/// Big endian two's complement encoding of an integer in `bits / 8` bytes, if it fits
+fn integer(negative: bool, magnitude: &[u8], signed: bool, bits: usize) -> Option<Vec<u8>> {
+    let magnitude = match magnitude.iter().position(|&byte| byte != 0) {
+        Some(start) => &magnitude[start..],
+        None => &[],
+    };
+    if magnitude.len() > 32 || negative && !signed {
+        return None;
+    }
+
+    let mut word = [0u8; 32];
+    word[32 - magnitude.len()..].copy_from_slice(magnitude);
+    if negative && !magnitude.is_empty() {
+        let mut carry = true;
+        for byte in word.iter_mut().rev() {
+            let (value, overflow) = (!*byte).overflowing_add(carry as u8);
+            *byte = value;
+            carry = overflow;
+        }
+    }
+
+    let (high, low) = word.split_at(32 - bits / 8);
+    let negative_value = signed && low[0] & 0x80 != 0;
+    let fill = if negative_value { 0xff } else { 0 };
+    if high.iter().any(|&byte| byte != fill) {
+        return None;
+    }
+    if signed && negative_value != (negative && !magnitude.is_empty()) {
+        return None;
+    }
+
+    Some(low.to_vec())
+}
+
+/// Part of a method name identifying an overload by the type of one of its parameters
+fn type_suffix(ty: &Ty) -> String {
+    match ty {
+        Ty::Elementary(ty, _) => ty.to_string(),
+        Ty::Named(name) => snake_case(&name.to_string()),
+        Ty::Array(ty, Some(len), _) => format!("{}_array{}", type_suffix(ty), len),
+        Ty::Array(ty, None, _) => format!("{}_array", type_suffix(ty)),
+    }
+}
+
+/// Keywords which cannot be used as identifiers in Rust
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// `snake_case` Rust identifier for a Solidity name, or `{fallback}{index}` if it has none
+fn rust_ident(name: Option<&Ident>, fallback: &str, index: usize) -> Ident {
+    let (mut ident, span) = match name {
+        Some(name) => (snake_case(&name.to_string()), name.span()),
+        None => (format!("{}{}", fallback, index), Span::call_site()),
+    };
+
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+
+    Ident::new(&ident, span)
+}
@@ -0,0 +1,73 @@
+use super::field::SolidField;
+use proc_macro2::{
+    Ident,
+    TokenStream,
+    TokenTree,
+};
+use syn::{
+    parse_quote,
+    Generics,
+    Type,
+    WherePredicate,
+};
+
+/// Whether `ident` appears anywhere in `tokens`
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(other) => other == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn uses(ty: &Type, ident: &Ident) -> bool {
+    mentions(quote! { #ty }, ident)
+}
+
+/// `generics` with each type parameter bounded by the traits its fields require
+///
+/// `bounds` returns the traits a field requires of its type, which are applied to the type
+/// parameters used in that field. Predicates given with `#[solid(bound = "...")]` replace the
+/// inferred ones.
+pub(crate) fn with_bounds<F>(
+    generics: &Generics,
+    fields: &[SolidField],
+    custom: Option<&[WherePredicate]>,
+    bounds: F,
+) -> Generics
+where
+    F: Fn(&SolidField) -> Vec<TokenStream>,
+{
+    let mut generics = generics.clone();
+
+    let predicates = match custom {
+        Some(custom) => custom.to_vec(),
+        None => generics
+            .type_params()
+            .filter_map(|param| {
+                let mut traits = Vec::<TokenStream>::new();
+
+                for field in fields.iter().filter(|field| uses(field.ty, &param.ident)) {
+                    for bound in bounds(field) {
+                        if !traits
+                            .iter()
+                            .any(|existing| existing.to_string() == bound.to_string())
+                        {
+                            traits.push(bound);
+                        }
+                    }
+                }
+
+                if traits.is_empty() {
+                    return None;
+                }
+
+                let ident = &param.ident;
+                Some(parse_quote! { #ident: #(#traits)+* })
+            })
+            .collect(),
+    };
+
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
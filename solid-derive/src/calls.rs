@@ -0,0 +1,215 @@
+use super::decode::decode_generics;
+use proc_macro2::{
+    Group,
+    Ident,
+    TokenStream,
+    TokenTree,
+};
+use syn::{
+    spanned::Spanned,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Result,
+    Type,
+};
+
+/// Variant of an enum deriving `Calls`, wrapping a single `#[derive(Encode, Decode)]` struct
+struct CallVariant<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+}
+
+fn variants(ast: &DeriveInput) -> Result<Vec<CallVariant<'_>>> {
+    let data = match &ast.data {
+        Data::Enum(data) => data,
+        Data::Struct(data) => {
+            return Err(Error::new(
+                data.struct_token.span,
+                "`Calls` can only be derived for enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`Calls` can only be derived for enums",
+            ))
+        }
+    };
+
+    if data.variants.is_empty() {
+        return Err(Error::new(
+            ast.ident.span(),
+            "`Calls` requires at least one variant",
+        ));
+    }
+
+    if let Some(param) = ast.generics.type_params().next() {
+        return Err(Error::new(
+            param.ident.span(),
+            "`Calls` does not support type parameters, only lifetimes",
+        ));
+    }
+
+    data.variants
+        .iter()
+        .map(|variant| match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(CallVariant {
+                ident: &variant.ident,
+                ty: &fields.unnamed[0].ty,
+            }),
+            _ => Err(Error::new(
+                variant.span(),
+                "each variant of a `Calls` enum must wrap exactly one call, e.g. `Transfer(Transfer)`",
+            )),
+        })
+        .collect()
+}
+
+/// `tokens` with every lifetime replaced by `'static`, so the type can be named outside of
+/// the enum's generics
+fn with_static_lifetimes(tokens: TokenStream) -> TokenStream {
+    let mut lifetime = false;
+
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                lifetime = false;
+                let mut replaced =
+                    Group::new(group.delimiter(), with_static_lifetimes(group.stream()));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            TokenTree::Punct(punct) => {
+                lifetime = punct.as_char() == '\'';
+                TokenTree::Punct(punct)
+            }
+            TokenTree::Ident(ident) if lifetime => {
+                lifetime = false;
+                TokenTree::Ident(Ident::new("static", ident.span()))
+            }
+            token => {
+                lifetime = false;
+                token
+            }
+        })
+        .collect()
+}
+
+pub(super) fn impl_calls(ast: &DeriveInput) -> Result<TokenStream> {
+    let ident = &ast.ident;
+
+    if let Some(attr) = ast.attrs.iter().find(|attr| attr.path.is_ident("solid")) {
+        return Err(Error::new(
+            attr.span(),
+            "`Calls` does not support solid attributes, the selectors come from the variants",
+        ));
+    }
+
+    let variants = variants(ast)?;
+    let count = variants.len();
+
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let generics = decode_generics(&ast.generics);
+    let (decode_impl_generics, _, _) = generics.split_for_impl();
+
+    let variant = variants
+        .iter()
+        .map(|variant| variant.ident)
+        .collect::<Vec<_>>();
+
+    // Every pair of variants is compared while compiling, so a collision fails the build
+    // instead of making one of the variants unreachable when decoding. Indexing past the end of
+    // a one element array is used rather than `panic!`, which is not allowed in constants on
+    // the pinned toolchain.
+    let mut collisions = Vec::new();
+    for (i, a) in variants.iter().enumerate() {
+        for b in &variants[i + 1..] {
+            let (a_ty, b_ty) = (a.ty, b.ty);
+            let a_ty = with_static_lifetimes(quote! { #a_ty });
+            let b_ty = with_static_lifetimes(quote! { #b_ty });
+            let check = format_ident!(
+                "SELECTORS_OF_{}_AND_{}_COLLIDE",
+                a.ident.to_string().to_uppercase(),
+                b.ident.to_string().to_uppercase()
+            );
+            collisions.push(quote_spanned! { b.ty.span() =>
+                const #check: () = [()][
+                    ((<#a_ty>::SELECTOR[0] == <#b_ty>::SELECTOR[0])
+                        & (<#a_ty>::SELECTOR[1] == <#b_ty>::SELECTOR[1])
+                        & (<#a_ty>::SELECTOR[2] == <#b_ty>::SELECTOR[2])
+                        & (<#a_ty>::SELECTOR[3] == <#b_ty>::SELECTOR[3])) as usize
+                ];
+            });
+        }
+    }
+
+    // Variants whose call has no `SELECTOR`, because the Solidity type of one of its fields is
+    // only known at runtime, fail to compile here rather than being matched at runtime
+    let selector = variants
+        .iter()
+        .map(|variant| {
+            let ty = variant.ty;
+            quote_spanned! { ty.span() => <#ty>::SELECTOR }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Selectors of the calls of every variant, in declaration order
+            pub const SELECTORS: [[u8; 4]; #count] = [#(#selector),*];
+
+            /// Selector of the call held by this variant
+            pub fn selector(&self) -> [u8; 4] {
+                match self {
+                    #(
+                        Self::#variant(_) => #selector,
+                    )*
+                }
+            }
+        }
+
+        impl #impl_generics solid::encode::Encode for #ident #ty_generics #where_clause {
+            fn encode(&self) -> Vec<u8> {
+                match self {
+                    #(
+                        Self::#variant(call) => solid::encode::Encode::encode(call),
+                    )*
+                }
+            }
+
+            fn required_len(&self) -> u64 {
+                match self {
+                    #(
+                        Self::#variant(call) => solid::encode::Encode::required_len(call),
+                    )*
+                }
+            }
+
+            fn is_dynamic() -> bool {
+                true
+            }
+        }
+
+        impl #decode_impl_generics solid::decode::Decode<'solidity> for #ident #ty_generics #where_clause {
+            fn decode(buf: &'solidity [u8]) -> solid::Result<Self> {
+                let call = solid::CallData::new(buf)?;
+                let selector = call.selector();
+
+                #(
+                    if selector == #selector {
+                        return Ok(Self::#variant(solid::decode::Decode::decode(call.args())?));
+                    }
+                )*
+
+                Err(solid::Error::UnknownSelector(selector))
+            }
+        }
+
+        const _: () = {
+            #(#collisions)*
+        };
+    })
+}
@@ -0,0 +1,79 @@
+use proc_macro2::TokenStream;
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+use syn::{
+    Error,
+    Ident,
+    LitStr,
+    Result,
+};
+
+/// Path of the file given to `contract!`, relative to the crate being compiled
+fn resolve(path: &LitStr) -> Result<PathBuf> {
+    let manifest = env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+        Error::new(
+            path.span(),
+            "`CARGO_MANIFEST_DIR` is not set, `contract!` must be compiled by cargo",
+        )
+    })?;
+
+    Ok(Path::new(&manifest).join(path.value()))
+}
+
+/// Generates the bindings `solid-build` writes for a JSON ABI, solc combined or standard JSON
+/// output, or a Foundry, Hardhat or Truffle artifact, each contract in a module named after
+/// its file
+pub(super) fn impl_contract(path: &LitStr) -> Result<TokenStream> {
+    let file = resolve(path)?;
+    let error = |message: String| Error::new(path.span(), message);
+
+    fs::read(&file).map_err(|err| error(format!("failed to read `{}`: {}", path.value(), err)))?;
+
+    let sources = solid_build::Generator::new()
+        .abi(&file)
+        .rerun_if_changed(false)
+        .sources()
+        .map_err(|err| error(err.to_string()))?;
+
+    if sources.is_empty() {
+        return Err(error(format!(
+            "`{}` does not contain any contract",
+            path.value()
+        )));
+    }
+
+    let modules = sources
+        .into_iter()
+        .map(|(name, source)| {
+            let source = source.parse::<TokenStream>().map_err(|err| {
+                error(format!(
+                    "invalid bindings generated for `{}`: {}",
+                    name, err
+                ))
+            })?;
+            let name = syn::parse_str::<Ident>(&name)
+                .map_err(|_| error(format!("`{}` cannot be used as a module name", name)))?;
+
+            Ok(quote! {
+                pub mod #name {
+                    #source
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let file = file.display().to_string();
+
+    Ok(quote! {
+        #(#modules)*
+
+        // Rebuilds the bindings whenever the file changes
+        const _: &[u8] = include_bytes!(#file);
+    })
+}
@@ -1,3 +1,12 @@
+use super::{
+    bound::with_bounds,
+    enums,
+    field::{
+        fields,
+        transparent_field,
+    },
+    StructAttrs,
+};
 use proc_macro2::{
     Span,
     TokenStream,
@@ -7,52 +16,143 @@ use syn::{
     token::Add,
     Data,
     DeriveInput,
-    Fields,
     GenericParam,
+    Generics,
     Lifetime,
     LifetimeDef,
+    Result,
 };
 
-pub(super) fn impl_decode(ast: &mut DeriveInput) -> TokenStream {
-    let ident = &ast.ident;
-
+/// `generics` with an added `'solidity` lifetime for the decoded buffer, which outlives
+/// every other lifetime
+pub(super) fn decode_generics(generics: &Generics) -> Generics {
     let mut lifetime = LifetimeDef::new(Lifetime::new("'solidity", Span::call_site()));
     let mut bounds = Punctuated::<Lifetime, Add>::new();
 
-    for param in ast.generics.lifetimes() {
+    for param in generics.lifetimes() {
         bounds.push(param.lifetime.clone());
     }
 
     lifetime.bounds = bounds;
 
-    let mut generics = ast.generics.clone();
-    generics.params.push(GenericParam::Lifetime(lifetime));
+    // Lifetimes have to be declared before type parameters
+    let mut generics = generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(lifetime));
+    generics
+}
 
-    let (_, ty_generics, where_clause) = &ast.generics.split_for_impl();
+/// `Decode` for a `#[solid(transparent)]` struct, decoding its field from the whole buffer
+fn impl_transparent(ast: &DeriveInput, attrs: &StructAttrs) -> Result<TokenStream> {
+    let ident = &ast.ident;
+    let fields = fields(ast, "Decode")?;
+    let field = transparent_field(ast, &fields)?;
 
-    let fields = match &ast.data {
-        Data::Struct(ref data) => match &data.fields {
-            Fields::Named(fields) => &fields.named,
-            Fields::Unnamed(fields) => &fields.unnamed,
-            Fields::Unit => return quote! {},
+    let generics = with_bounds(
+        &decode_generics(&ast.generics),
+        &fields,
+        attrs.bound.as_deref(),
+        |field| {
+            if field.skip {
+                vec![quote! { Default }]
+            } else {
+                vec![quote! { solid::decode::Decode<'solidity> }]
+            }
         },
+    );
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let member = fields.iter().map(|field| &field.member);
+    let value = fields.iter().map(|other| {
+        if other.skip {
+            return quote! { Default::default() };
+        }
+
+        let ty = field.ty;
+        let check = field.check_range();
+        match &field.with {
+            Some(with) => quote! { #with::decode(buf)? },
+            None => quote! {
+                {
+                    #check
+                    <#ty as solid::decode::Decode>::decode(buf)?
+                }
+            },
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics solid::decode::Decode<'solidity> for #ident #ty_generics #where_clause {
+            fn decode(buf: &'solidity [u8]) -> solid::Result<Self> {
+                Ok(Self {
+                    #(
+                        #member: #value,
+                    )*
+                })
+            }
+        }
+    })
+}
 
-        _ => panic!("Solidity does not support enums are unsupported"),
-    };
+pub(super) fn impl_decode(ast: &DeriveInput) -> Result<TokenStream> {
+    let ident = &ast.ident;
 
-    let field = fields.iter().filter_map(|field| field.ident.as_ref());
+    if let Data::Enum(data) = &ast.data {
+        return enums::impl_decode(ast, data);
+    }
+
+    let attrs = StructAttrs::new(ast)?;
+    if attrs.transparent {
+        return impl_transparent(ast, &attrs);
+    }
 
-    let ty = fields.iter().filter_map(|field| {
-        if let Some(_) = field.ident {
-            Some(field.ty.clone())
+    let fields = fields(ast, "Decode")?;
+
+    let generics = with_bounds(
+        &decode_generics(&ast.generics),
+        &fields,
+        attrs.bound.as_deref(),
+        |field| {
+            if field.skip {
+                return vec![quote! { Default }];
+            }
+
+            // Only fields with both `with` and `ty` take their head from the Solidity type
+            let mut bounds = Vec::new();
+            if field.with.is_none() {
+                bounds.push(quote! { solid::decode::Decode<'solidity> });
+            }
+            if field.with.is_none() || field.sol_ty.is_none() {
+                bounds.push(quote! { solid::encode::Encode });
+            }
+            bounds
+        },
+    );
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let member = fields.iter().map(|field| &field.member);
+    let value = fields.iter().map(|field| {
+        if field.skip {
+            quote! { Default::default() }
         } else {
-            None
+            let decode = field.decode();
+            let head_len = field.head_len();
+            quote! {
+                {
+                    let value = #decode;
+                    head += #head_len as usize;
+                    value
+                }
+            }
         }
     });
 
-    quote! {
-        impl #generics solid::decode::Decode<'solidity> for #ident #ty_generics #where_clause {
-            fn decode(buf: &'solidity [u8]) -> Self {
+    Ok(quote! {
+        impl #impl_generics solid::decode::Decode<'solidity> for #ident #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn decode(buf: &'solidity [u8]) -> solid::Result<Self> {
                 // Solidity returns the function signature for "Error(string)" if a function throws an error.
                 // To get around this simply check if the buffer is a factor of 32 or not. This is valid since
                 // solidity standard encoding format enforces that all fields line up to a 32 byte boundry.
@@ -62,23 +162,14 @@ pub(super) fn impl_decode(ast: &mut DeriveInput) -> TokenStream {
                     &buf
                 };
 
-                let mut index = 0;
+                let mut head = 0usize;
 
-                Self {
+                Ok(Self {
                     #(
-                        #field: {
-                            let value = if <#ty as solid::encode::Encode>::is_dynamic() {
-                                let offset = u64::decode(&buf[index * 32..(index + 1) * 32]) as usize;
-                                <#ty as solid::decode::Decode>::decode(&buf[offset..])
-                            } else {
-                                <#ty as solid::decode::Decode>::decode(&buf[index * 32..(index + 1) * 32])
-                            };
-                            index += 1;
-                            value
-                        },
+                        #member: #value,
                     )*
-                }
+                })
             }
         }
-    }
+    })
 }
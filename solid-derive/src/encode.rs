@@ -1,110 +1,230 @@
-use super::Solidity;
-use proc_macro2::{
-    Literal,
-    TokenStream,
+use super::{
+    bound::with_bounds,
+    enums,
+    field::{
+        fields,
+        transparent_field,
+        SolidField,
+    },
+    StructAttrs,
 };
+use proc_macro2::TokenStream;
+use solid_core::selector::Selector;
 use syn::{
     Data,
     DeriveInput,
-    Fields,
+    Result,
 };
 
-pub(super) fn impl_encode(ast: &DeriveInput) -> TokenStream {
+/// `Encode` and `IntoType` for a `#[solid(transparent)]` struct, forwarding to its field
+fn impl_transparent(ast: &DeriveInput, attrs: &StructAttrs) -> Result<TokenStream> {
     let ident = &ast.ident;
+    let fields = fields(ast, "Encode")?;
+    let field = transparent_field(ast, &fields)?;
+    let member = &field.member;
 
-    let mut has_name = true;
-    let mut name = Literal::string(ident.to_string().as_str());
-    for attr in &ast.attrs {
-        if let Some(ident) = &attr.path.get_ident() {
-            if ident.to_string() == "solid" {
-                let attribute = attr.parse_args::<Solidity>().unwrap();
-                match attribute.ident.to_string().as_str() {
-                    "constructor" => {
-                        has_name = false;
-                    }
-
-                    "rename" => {
-                        name = attribute.name.unwrap();
-                    }
-
-                    attribute => panic!("Unsupported key for solidity attribute: {:?}. Supported attribute keys are `rename` and `constructor`", attribute),
-                }
+    let generics = with_bounds(&ast.generics, &fields, attrs.bound.as_deref(), |field| {
+        if field.skip {
+            Vec::new()
+        } else {
+            vec![
+                quote! { solid::encode::Encode },
+                quote! { solid::into_type::IntoType },
+            ]
+        }
+    });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (encode, encode_param) = match (&field.with, field.range) {
+        (None, None) => (
+            quote! { solid::encode::Encode::encode(&self.#member) },
+            quote! { solid::encode::Encode::encode_param(&self.#member) },
+        ),
+        _ => (field.encode(), field.encode()),
+    };
+    let required_len = field.required_len();
+    let is_dynamic = field.is_dynamic();
+    let head_len = field.head_len();
+    let into_type = field.sol_type();
+    let ty = field.ty;
+    let param = match &field.sol_ty {
+        Some(sol_ty) => quote! { solid::abi::Param::from_type(name, #sol_ty) },
+        None => quote! { <#ty as solid::into_type::IntoType>::param(name) },
+    };
+
+    Ok(quote! {
+        impl #impl_generics solid::encode::Encode for #ident #ty_generics #where_clause {
+            fn encode(&self) -> Vec<u8> {
+                #encode
+            }
+
+            fn encode_param(&self) -> Vec<u8> {
+                #encode_param
+            }
+
+            fn required_len(&self) -> u64 {
+                #required_len
+            }
+
+            fn is_dynamic() -> bool {
+                #is_dynamic
+            }
+
+            fn head_len() -> u64 {
+                #head_len
             }
         }
+
+        impl #impl_generics solid::into_type::IntoType for #ident #ty_generics #where_clause {
+            fn into_type() -> std::borrow::Cow<'static, str> {
+                #into_type
+            }
+
+            fn param(name: &str) -> solid::abi::Param {
+                #param
+            }
+        }
+    })
+}
+
+pub(super) fn impl_encode(ast: &DeriveInput) -> Result<TokenStream> {
+    let ident = &ast.ident;
+
+    if let Data::Enum(data) = &ast.data {
+        return enums::impl_encode(ast, data);
     }
 
+    let attrs = StructAttrs::new(ast)?;
+    if attrs.transparent {
+        return impl_transparent(ast, &attrs);
+    }
+    let has_name = !attrs.constructor;
+    let name = &attrs.name;
+
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let fields = match &ast.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(fields) => &fields.named,
-            Fields::Unnamed(fields) => &fields.unnamed,
-            Fields::Unit => todo!(),
-        },
+    let fields = fields(ast, "Encode")?
+        .into_iter()
+        .filter(|field| !field.skip)
+        .collect::<Vec<_>>();
+
+    let into_type_generics = with_bounds(&ast.generics, &fields, attrs.bound.as_deref(), |field| {
+        if field.sol_ty.is_none() {
+            vec![quote! { solid::into_type::IntoType }]
+        } else {
+            Vec::new()
+        }
+    });
+
+    let encode_generics = with_bounds(&ast.generics, &fields, attrs.bound.as_deref(), |field| {
+        let mut bounds = Vec::new();
+        if field.with.is_none() || field.sol_ty.is_none() {
+            bounds.push(quote! { solid::encode::Encode });
+        }
+        if has_name && field.sol_ty.is_none() {
+            bounds.push(quote! { solid::into_type::IntoType });
+        }
+        bounds
+    });
 
-        _ => todo!(),
+    // The signature and selector are computed here when the Solidity type of every field is
+    // known, otherwise the selector is hashed at runtime from the fields' `IntoType`.
+    let signature = fields
+        .iter()
+        .map(SolidField::static_type)
+        .collect::<Option<Vec<_>>>()
+        .filter(|_| has_name)
+        .map(|types| format!("{}({})", name.value(), types.join(",")));
+
+    let selector = match &signature {
+        Some(_) => quote! { Self::SELECTOR },
+        None => quote! {
+            solid::Selector::from_signature(&format!(
+                "{}{}",
+                #name,
+                <Self as solid::into_type::IntoType>::into_type()
+            ))
+        },
     };
 
-    let count = fields.iter().count();
+    let consts = signature.map(|signature| {
+        let selector = Selector::from_signature(&signature);
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Canonical signature of the function called by this struct
+                pub const SIGNATURE: &'static str = #signature;
 
-    let field = fields.iter().map(|field| field.ident.clone());
+                /// Selector of the function called by this struct
+                pub const SELECTOR: [u8; 4] = [#(#selector),*];
+            }
+        }
+    });
 
-    let ty1 = fields.iter().map(|field| field.ty.clone());
-    let ty2 = fields.iter().map(|field| field.ty.clone());
-    let ty3 = fields.iter().map(|field| field.ty.clone());
-    let ty4 = fields.iter().map(|field| field.ty.clone());
+    let encode_field = fields.iter().map(SolidField::encode);
+    let is_dynamic1 = fields.iter().map(SolidField::is_dynamic);
+    let is_dynamic2 = fields.iter().map(SolidField::is_dynamic);
+    let is_dynamic3 = fields.iter().map(SolidField::is_dynamic);
+    let head_len1 = fields.iter().map(SolidField::head_len);
+    let head_len2 = fields.iter().map(SolidField::head_len);
+    let head_len3 = fields.iter().map(SolidField::head_len);
+    let head_len4 = fields.iter().map(SolidField::head_len);
+    let required_len = fields.iter().map(SolidField::required_len);
+    let into_type = fields.iter().map(SolidField::sol_type);
+    let param = fields.iter().map(SolidField::param);
+    let internal_type = format!("struct {}", ident);
 
     let encode = quote! {
         fn encode(&self) -> Vec<u8> {
-            let name_offset: usize = if #has_name {
-                4
+            if #has_name {
+                let mut buf = #selector.to_vec();
+                buf.extend_from_slice(&self.encode_param());
+                buf
             } else {
-                0
-            };
-
-            let len = self.required_len() + name_offset as u64;
-
-            let mut buf = vec![0u8; len as usize];
+                self.encode_param()
+            }
+        }
+    };
 
-            let mut offset = (#count * 32) as usize + name_offset;
-            let mut index = 0usize;
+    // The struct is encoded as a tuple of its fields, with the head of each field being its
+    // offset if it is dynamic or its encoding in place otherwise.
+    let encode_param = quote! {
+        #[allow(unused_mut, unused_variables)]
+        fn encode_param(&self) -> Vec<u8> {
+            let mut buf = vec![0u8; self.required_len() as usize];
 
-            if #has_name {
-                let mut selector = solid::Selector::new();
-                #(
-                    selector = selector.push::<#ty1>();
-                )*
+            let mut offset = 0usize;
+            #(
+                offset += #head_len1 as usize;
+            )*
 
-                buf[0..4].copy_from_slice(&selector.build(#name));
-            }
+            let mut head = 0usize;
 
             #(
-                let bytes = self.#field.encode();
-                if <#ty2 as solid::encode::Encode>::is_dynamic() {
-                    buf[index * 32 + 24..(index + 1) * 32].copy_from_slice(&(offset as u64).to_be_bytes());
+                let bytes = #encode_field;
+                if #is_dynamic1 {
+                    buf[head + 24..head + 32].copy_from_slice(&(offset as u64).to_be_bytes());
                     buf[offset..offset + bytes.len()].copy_from_slice(&bytes);
                     offset += bytes.len();
                 } else {
-                    buf[index * 32 + name_offset..(index + 1) * 32 + name_offset].copy_from_slice(&bytes);
+                    buf[head..head + bytes.len()].copy_from_slice(&bytes);
                 }
-                index += 1;
+                head += #head_len2 as usize;
             )*
 
             buf
         }
     };
 
-    let field = fields.iter().map(|field| field.ident.clone());
-
     let required_len = quote! {
+        #[allow(unused_mut)]
         fn required_len(&self) -> u64 {
             let mut len = 0u64;
 
             #(
-                len += if <#ty3 as solid::encode::Encode>::is_dynamic() {
-                    32 + self.#field.required_len()
+                len += if #is_dynamic2 {
+                    32 + #required_len
                 } else {
-                    32
+                    #head_len3
                 };
             )*
 
@@ -114,32 +234,70 @@ pub(super) fn impl_encode(ast: &DeriveInput) -> TokenStream {
 
     let is_dynamic = quote! {
         fn is_dynamic() -> bool {
-            true
+            false #(|| #is_dynamic3)*
+        }
+
+        fn head_len() -> u64 {
+            if <Self as solid::encode::Encode>::is_dynamic() {
+                32
+            } else {
+                0u64 #(+ #head_len4 as u64)*
+            }
         }
     };
 
     let into_type = quote! {
+        #[allow(unused_mut)]
         fn into_type() -> std::borrow::Cow::<'static, str> {
-            let mut ty = Vec::new();
+            let mut ty = Vec::<std::borrow::Cow<'static, str>>::new();
             #(
-                ty.push(<#ty4 as solid::into_type::IntoType>::into_type());
+                ty.push(#into_type);
             )*
 
             std::borrow::Cow::Owned(format!("({})", ty.join(",")))
         }
+
+        fn param(name: &str) -> solid::abi::Param {
+            solid::abi::Param::tuple(name, Some(#internal_type.to_string()), vec![#(#param),*])
+        }
     };
 
-    quote! {
-        impl #impl_generics solid::encode::Encode for #ident #ty_generics #where_clause {
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+    let (into_type_impl_generics, _, into_type_where_clause) = into_type_generics.split_for_impl();
+
+    let selector_fn = if has_name {
+        Some(quote! {
+            impl #encode_impl_generics #ident #ty_generics #encode_where_clause {
+                /// Selector of the function called by this struct
+                ///
+                /// This is `SELECTOR` when the signature is known at compile time, and is hashed
+                /// from the fields' `IntoType` otherwise.
+                pub fn selector() -> [u8; 4] {
+                    #selector
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    Ok(quote! {
+        impl #encode_impl_generics solid::encode::Encode for #ident #ty_generics #encode_where_clause {
             #encode
 
+            #encode_param
+
             #required_len
 
             #is_dynamic
         }
 
-        impl #impl_generics solid::into_type::IntoType for #ident #ty_generics #where_clause {
+        impl #into_type_impl_generics solid::into_type::IntoType for #ident #ty_generics #into_type_where_clause {
             #into_type
         }
-    }
+
+        #consts
+
+        #selector_fn
+    })
 }
@@ -0,0 +1,131 @@
+use super::{
+    decode::decode_generics,
+    parse_attributes,
+};
+use proc_macro2::TokenStream;
+use syn::{
+    spanned::Spanned,
+    DataEnum,
+    DeriveInput,
+    Error,
+    Fields,
+    Ident,
+    Result,
+};
+
+/// Variants of a fieldless enum, which are numbered in declaration order like Solidity enums
+fn variants<'a>(ast: &DeriveInput, data: &'a DataEnum, derive: &str) -> Result<Vec<&'a Ident>> {
+    if let Some(attribute) = parse_attributes(&ast.attrs)?.first() {
+        return Err(Error::new(
+            attribute.ident.span(),
+            format!(
+                "unsupported key `{}` for solid attribute, enums do not take any keys",
+                attribute.ident
+            ),
+        ));
+    }
+
+    if data.variants.is_empty() {
+        return Err(Error::new(
+            ast.ident.span(),
+            format!("`{}` requires at least one variant", derive),
+        ));
+    }
+
+    if data.variants.len() > 256 {
+        return Err(Error::new(
+            ast.ident.span(),
+            "Solidity enums have at most 256 variants",
+        ));
+    }
+
+    data.variants
+        .iter()
+        .map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(Error::new(
+                    variant.span(),
+                    format!(
+                        "`{}` can only be derived for enums without fields, which are encoded as `uint8`",
+                        derive
+                    ),
+                ));
+            }
+
+            if let Some((_, discriminant)) = &variant.discriminant {
+                return Err(Error::new(
+                    discriminant.span(),
+                    "explicit discriminants are not supported, Solidity enums are numbered in declaration order",
+                ));
+            }
+
+            Ok(&variant.ident)
+        })
+        .collect()
+}
+
+pub(super) fn impl_encode(ast: &DeriveInput, data: &DataEnum) -> Result<TokenStream> {
+    let ident = &ast.ident;
+    let variant = variants(ast, data, "Encode")?;
+    let index = (0..variant.len()).map(|index| index as u8);
+    let internal_type = format!("enum {}", ident);
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics solid::encode::Encode for #ident #ty_generics #where_clause {
+            fn encode(&self) -> Vec<u8> {
+                let value: u8 = match self {
+                    #(
+                        Self::#variant => #index,
+                    )*
+                };
+
+                solid::encode::Encode::encode(&value)
+            }
+        }
+
+        impl #impl_generics solid::into_type::IntoType for #ident #ty_generics #where_clause {
+            fn into_type() -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed("uint8")
+            }
+
+            fn param(name: &str) -> solid::abi::Param {
+                solid::abi::Param::from_type(name, "uint8").internal_type(#internal_type)
+            }
+        }
+    })
+}
+
+pub(super) fn impl_decode(ast: &DeriveInput, data: &DataEnum) -> Result<TokenStream> {
+    let ident = &ast.ident;
+    let variant = variants(ast, data, "Decode")?;
+    let index = (0..variant.len()).map(|index| index as u8);
+    let message = format!("value out of range for enum `{}`", ident);
+
+    let generics = decode_generics(&ast.generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics solid::decode::Decode<'solidity> for #ident #ty_generics #where_clause {
+            fn decode(buf: &'solidity [u8]) -> solid::Result<Self> {
+                let word = solid::decode::word(buf, 0)?;
+
+                // The whole word is checked so that values above 255 are not truncated into
+                // a valid variant
+                if word[..31].iter().any(|&byte| byte != 0) {
+                    return Err(solid::Error::Message(#message.to_string()));
+                }
+
+                #[allow(unreachable_patterns)]
+                match word[31] {
+                    #(
+                        #index => Ok(Self::#variant),
+                    )*
+                    _ => Err(solid::Error::Message(#message.to_string())),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,318 @@
+use super::{
+    parse_attributes,
+    types::resolve,
+};
+use proc_macro2::{
+    Span,
+    TokenStream,
+};
+use solid_core::value::SolType;
+use syn::{
+    Data,
+    DeriveInput,
+    Error,
+    Field,
+    Ident,
+    Index,
+    LitStr,
+    Member,
+    Path,
+    Result,
+    Type,
+};
+
+/// Field of a struct deriving `Encode` or `Decode` along with its `#[solid(...)]` attributes
+pub(crate) struct SolidField<'a> {
+    pub member: Member,
+    pub ty: &'a Type,
+    /// `#[solid(ty = "...")]`: Solidity type used in place of the field type's `IntoType`
+    ///
+    /// An integer field narrowed to a smaller Solidity integer makes `encode` panic when its
+    /// value does not fit, see `range`.
+    pub sol_ty: Option<LitStr>,
+    /// `#[solid(skip)]`: field is not encoded and is set to `Default::default()` on decode
+    pub skip: bool,
+    /// `#[solid(with = "...")]`: module providing `encode` and `decode` for the field
+    pub with: Option<Path>,
+    /// `#[solid(rename = "...")]`: name of the field in ABI JSON and EIP-712 type strings
+    pub name: LitStr,
+    /// `#[solid(indexed)]`: the event parameter is a topic
+    pub indexed: Option<Ident>,
+    /// Width and signedness of an integer `ty` narrower than the field type, which is checked
+    /// whenever the field is encoded or decoded
+    pub range: Option<(usize, bool)>,
+}
+
+impl<'a> SolidField<'a> {
+    pub fn new(index: usize, field: &'a Field) -> Result<Self> {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+
+        let name = match &field.ident {
+            Some(ident) => LitStr::new(&ident.to_string(), ident.span()),
+            None => LitStr::new(&index.to_string(), Span::call_site()),
+        };
+
+        let mut solid_field = SolidField {
+            member,
+            ty: &field.ty,
+            sol_ty: None,
+            skip: false,
+            with: None,
+            name,
+            indexed: None,
+            range: None,
+        };
+
+        let attributes = parse_attributes(&field.attrs)?;
+        for attribute in &attributes {
+            match attribute.ident.to_string().as_str() {
+                "ty" => {
+                    // Stored in its canonical form, e.g. `uint` becomes `uint256`
+                    let sol_ty = attribute.value()?;
+                    let canonical = SolType::parse(&sol_ty.value())
+                        .map_err(|err| Error::new(sol_ty.span(), err))?;
+                    solid_field.sol_ty = Some(LitStr::new(&canonical.to_string(), sol_ty.span()));
+                }
+
+                "skip" => {
+                    attribute.flag()?;
+                    solid_field.skip = true;
+                }
+
+                "with" => {
+                    let with = attribute.value()?;
+                    solid_field.with = Some(with.parse::<Path>().map_err(|_| {
+                        Error::new(
+                            with.span(),
+                            "`with` must be the path to a module, e.g. `with = \"codec\"`",
+                        )
+                    })?);
+                }
+
+                "rename" => solid_field.name = attribute.value()?,
+
+                "indexed" => {
+                    attribute.flag()?;
+                    solid_field.indexed = Some(attribute.ident.clone());
+                }
+
+                _ => {
+                    return Err(Error::new(
+                        attribute.ident.span(),
+                        format!(
+                            "unsupported key `{}` for solid field attribute, supported keys are `ty`, `skip`, `with`, `rename` and `indexed`",
+                            attribute.ident
+                        ),
+                    ))
+                }
+            }
+        }
+
+        if solid_field.skip {
+            if let Some(attribute) = attributes
+                .iter()
+                .find(|attribute| attribute.ident == "ty" || attribute.ident == "with")
+            {
+                return Err(Error::new(
+                    attribute.ident.span(),
+                    format!("`{}` cannot be combined with `skip`", attribute.ident),
+                ));
+            }
+        }
+
+        if let (Some(sol_ty), None) = (&solid_field.sol_ty, &solid_field.with) {
+            solid_field.range = range(sol_ty, solid_field.ty)?;
+        }
+
+        Ok(solid_field)
+    }
+
+    /// Solidity type of the field, if it is known while expanding the macro
+    pub fn static_type(&self) -> Option<String> {
+        match &self.sol_ty {
+            Some(sol_ty) => Some(sol_ty.value()),
+            None => resolve(self.ty),
+        }
+    }
+
+    /// Expression evaluating to the Solidity type of the field
+    pub fn sol_type(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.sol_ty {
+            Some(sol_ty) => quote! { std::borrow::Cow::<'static, str>::Borrowed(#sol_ty) },
+            None => quote! { <#ty as solid::into_type::IntoType>::into_type() },
+        }
+    }
+
+    /// Expression evaluating to the field as a parameter of a JSON ABI entry
+    pub fn param(&self) -> TokenStream {
+        let ty = self.ty;
+        let name = &self.name;
+        match &self.sol_ty {
+            Some(sol_ty) => quote! { solid::abi::Param::from_type(#name, #sol_ty) },
+            None => quote! { <#ty as solid::into_type::IntoType>::param(#name) },
+        }
+    }
+
+    /// Expression evaluating to whether the field is encoded in the tail
+    ///
+    /// Only fields encoded by a `with` module, whose type need not implement `Encode`, rely on
+    /// their `ty` attribute.
+    pub fn is_dynamic(&self) -> TokenStream {
+        let ty = self.ty;
+        match self.with_type() {
+            Some(sol_ty) => {
+                let is_dynamic = sol_ty.is_dynamic();
+                quote! { #is_dynamic }
+            }
+            None => quote! { <#ty as solid::encode::Encode>::is_dynamic() },
+        }
+    }
+
+    /// Expression evaluating to the number of bytes the field takes up in the head
+    pub fn head_len(&self) -> TokenStream {
+        let ty = self.ty;
+        match self.with_type() {
+            Some(sol_ty) => {
+                let head_len = sol_ty.head_len() as u64;
+                quote! { #head_len }
+            }
+            None => quote! { <#ty as solid::encode::Encode>::head_len() },
+        }
+    }
+
+    /// Solidity type of a field encoded by a `with` module
+    fn with_type(&self) -> Option<SolType> {
+        self.with.as_ref()?;
+        SolType::parse(&self.sol_ty.as_ref()?.value()).ok()
+    }
+
+    /// Expression encoding the field of `self`
+    ///
+    /// Panics if a field narrowed by `ty` holds a value which does not fit into its Solidity type.
+    pub fn encode(&self) -> TokenStream {
+        let member = &self.member;
+        let name = &self.name;
+        match (&self.with, self.range) {
+            (Some(with), _) => quote! { #with::encode(&self.#member) },
+            (None, Some((bits, signed))) => quote! {
+                {
+                    let buf = solid::encode::Encode::encode_param(&self.#member);
+                    if let Err(err) = solid::decode::check_int(&buf, #bits, #signed) {
+                        panic!("cannot encode field `{}`: {}", #name, err);
+                    }
+                    buf
+                }
+            },
+            (None, None) => quote! { solid::encode::Encode::encode_param(&self.#member) },
+        }
+    }
+
+    /// Expression evaluating to the encoded length of the field of `self`
+    pub fn required_len(&self) -> TokenStream {
+        let member = &self.member;
+        match &self.with {
+            Some(with) => quote! { #with::encode(&self.#member).len() as u64 },
+            None => quote! { solid::encode::Encode::required_len(&self.#member) },
+        }
+    }
+
+    /// Statement failing unless the encoded field in `buf` fits into the integer type of `ty`
+    pub fn check_range(&self) -> Option<TokenStream> {
+        self.range.map(|(bits, signed)| {
+            quote! { solid::decode::check_int(buf, #bits, #signed)?; }
+        })
+    }
+
+    /// Expression decoding the field from the member of the tuple in `buf` whose head starts at
+    /// `head`
+    pub fn decode(&self) -> TokenStream {
+        let ty = self.ty;
+        let is_dynamic = self.is_dynamic();
+        let head_len = self.head_len();
+        let decode = match &self.with {
+            Some(with) => quote! { #with::decode },
+            None => quote! { <#ty as solid::decode::Decode>::decode },
+        };
+
+        let check = self.check_range();
+
+        quote! {
+            {
+                let buf = solid::decode::param_at(buf, head, #is_dynamic, #head_len as usize)?;
+                #check
+                #decode(buf)?
+            }
+        }
+    }
+}
+
+/// Checks that a field of type `ty` can be encoded as the Solidity type `sol_ty`
+///
+/// Field types whose Solidity type is known while expanding the macro must either encode
+/// exactly like `sol_ty`, or be an integer at least as wide as `sol_ty`, whose range is then
+/// returned to be checked at runtime. Other field types are trusted to encode like `sol_ty`.
+fn range(sol_ty: &LitStr, ty: &Type) -> Result<Option<(usize, bool)>> {
+    let encoded = match resolve(ty).and_then(|ty| SolType::parse(&ty).ok()) {
+        Some(encoded) => encoded,
+        None => return Ok(None),
+    };
+
+    let expected = SolType::parse(&sol_ty.value()).map_err(|err| Error::new(sol_ty.span(), err))?;
+    match (&expected, &encoded) {
+        _ if expected == encoded => Ok(None),
+        (SolType::Uint(bits), SolType::Uint(width)) if bits <= width => Ok(Some((*bits, false))),
+        (SolType::Int(bits), SolType::Int(width)) if bits <= width => Ok(Some((*bits, true))),
+        _ => Err(Error::new(
+            sol_ty.span(),
+            format!(
+                "a field encoded as `{}` cannot be used as `{}`, use `with` to convert it",
+                encoded, expected
+            ),
+        )),
+    }
+}
+
+/// Fields of the struct `ast`, rejecting unions which `derive` does not support
+///
+/// Unit structs have no fields, which makes them zero argument calls. Enums are handled
+/// separately and only reach this for derives which do not support them.
+pub(crate) fn fields<'a>(ast: &'a DeriveInput, derive: &str) -> Result<Vec<SolidField<'a>>> {
+    match &ast.data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| SolidField::new(index, field))
+            .collect(),
+
+        Data::Enum(data) => Err(Error::new(
+            data.enum_token.span,
+            format!("`{}` cannot be derived for enums", derive),
+        )),
+
+        Data::Union(data) => Err(Error::new(
+            data.union_token.span,
+            format!("`{}` cannot be derived for unions", derive),
+        )),
+    }
+}
+
+/// The only field of a `#[solid(transparent)]` struct which is not skipped
+pub(crate) fn transparent_field<'a, 'b>(
+    ast: &DeriveInput,
+    fields: &'b [SolidField<'a>],
+) -> Result<&'b SolidField<'a>> {
+    let mut encoded = fields.iter().filter(|field| !field.skip);
+
+    match (encoded.next(), encoded.next()) {
+        (Some(field), None) => Ok(field),
+        _ => Err(Error::new(
+            ast.ident.span(),
+            "`transparent` requires exactly one field which is not skipped",
+        )),
+    }
+}
@@ -0,0 +1,323 @@
+use super::{
+    parse_attributes,
+    types::resolve,
+};
+use proc_macro2::TokenStream;
+use syn::{
+    spanned::Spanned,
+    Error,
+    FnArg,
+    GenericParam,
+    Ident,
+    ItemTrait,
+    LitStr,
+    Pat,
+    Result,
+    ReturnType,
+    TraitItem,
+    TraitItemMethod,
+    Type,
+};
+
+/// `snake_case` to `PascalCase`
+pub(super) fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `snake_case` to `camelCase`, the convention for Solidity function names
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `PascalCase` to `snake_case`, splitting acronyms from the following word so that `IVault`
+/// becomes `i_vault`
+pub(super) fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_lower = matches!(chars.get(index + 1), Some(next) if next.is_lowercase());
+            if !previous.is_uppercase() && previous != '_' || previous.is_uppercase() && next_lower
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// Method of the interface trait along with its `#[solid(...)]` attributes
+struct Method {
+    /// Name of the generated call struct
+    ident: Ident,
+    /// Name of the Solidity function
+    name: LitStr,
+    /// `view`, `pure` or `payable`
+    mutability: Option<Ident>,
+    lifetimes: Vec<GenericParam>,
+    args: Vec<(Ident, Type)>,
+    output: Option<Type>,
+}
+
+impl Method {
+    /// Parses `method`, removing the `#[solid(...)]` attributes the trait cannot keep
+    fn new(method: &mut TraitItemMethod) -> Result<Self> {
+        let sig = &method.sig;
+        let mut name = LitStr::new(&camel_case(&sig.ident.to_string()), sig.ident.span());
+        let mut mutability = None;
+
+        for attribute in parse_attributes(&method.attrs)? {
+            match attribute.ident.to_string().as_str() {
+                "rename" => name = attribute.value()?,
+                "view" | "pure" | "payable" => {
+                    attribute.flag()?;
+                    if mutability.is_some() {
+                        return Err(Error::new(
+                            attribute.ident.span(),
+                            "only one of `view`, `pure` and `payable` can be used",
+                        ));
+                    }
+                    mutability = Some(attribute.ident.clone());
+                }
+                _ => {
+                    return Err(Error::new(
+                        attribute.ident.span(),
+                        format!(
+                            "unsupported key `{}` for solid method attribute, supported keys are `rename`, `view`, `pure` and `payable`",
+                            attribute.ident
+                        ),
+                    ))
+                }
+            }
+        }
+
+        method.attrs.retain(|attr| !attr.path.is_ident("solid"));
+        let sig = &method.sig;
+
+        let mut lifetimes = Vec::new();
+        for param in &sig.generics.params {
+            match param {
+                GenericParam::Lifetime(_) => lifetimes.push(param.clone()),
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "interface methods cannot have type or const parameters",
+                    ))
+                }
+            }
+        }
+
+        let mut args = Vec::new();
+        for input in &sig.inputs {
+            let arg = match input {
+                FnArg::Receiver(_) => continue,
+                FnArg::Typed(arg) => arg,
+            };
+
+            let ident = match &*arg.pat {
+                Pat::Ident(pat) => pat.ident.clone(),
+                pat => {
+                    return Err(Error::new(
+                        pat.span(),
+                        "arguments of interface methods must be named",
+                    ))
+                }
+            };
+
+            if let Type::Reference(reference) = &*arg.ty {
+                if reference.lifetime.is_none() {
+                    return Err(Error::new(
+                        reference.span(),
+                        "references in interface methods need explicit lifetimes, e.g. `fn f<'a>(&self, memo: &'a str)`",
+                    ));
+                }
+            }
+
+            // The selectors of `Calls` are constants, so every argument's Solidity type has to be
+            // known while expanding the macro
+            if resolve(&arg.ty).is_none() {
+                return Err(Error::new(
+                    arg.ty.span(),
+                    format!(
+                        "the Solidity type of argument `{}` is not known at compile time, use a type provided by `solid`, a primitive, or a `Vec`, slice or tuple of those",
+                        ident
+                    ),
+                ));
+            }
+
+            args.push((ident, (*arg.ty).clone()));
+        }
+
+        let output = match &sig.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => match &**ty {
+                Type::Tuple(tuple) if tuple.elems.is_empty() => None,
+                ty => Some(ty.clone()),
+            },
+        };
+
+        Ok(Method {
+            ident: Ident::new(&pascal_case(&sig.ident.to_string()), sig.ident.span()),
+            name,
+            mutability,
+            lifetimes,
+            args,
+            output,
+        })
+    }
+
+    /// Types of the values returned by the function, which are the members of the tuple the
+    /// return data is encoded as
+    fn outputs(&self) -> Vec<&Type> {
+        match &self.output {
+            None => Vec::new(),
+            Some(Type::Tuple(tuple)) => tuple.elems.iter().collect(),
+            Some(ty) => vec![ty],
+        }
+    }
+}
+
+pub(super) fn impl_interface(item: &mut ItemTrait) -> Result<TokenStream> {
+    let mut methods = Vec::new();
+    for trait_item in &mut item.items {
+        if let TraitItem::Method(method) = trait_item {
+            methods.push(Method::new(method)?);
+        }
+    }
+
+    if methods.is_empty() {
+        return Err(Error::new(
+            item.ident.span(),
+            "`interface` requires at least one method",
+        ));
+    }
+
+    let vis = &item.vis;
+    let contract = item.ident.to_string();
+    let module = Ident::new(&snake_case(&contract), item.ident.span());
+    let doc = format!(
+        "Calls, return values and ABI of the `{}` interface",
+        contract
+    );
+
+    let calls = methods.iter().map(|method| {
+        let ident = &method.ident;
+        let name = &method.name;
+        let lifetimes = &method.lifetimes;
+        let arg = method.args.iter().map(|(ident, _)| ident);
+        let arg_ty = method.args.iter().map(|(_, ty)| ty);
+        let mutability = &method.mutability;
+        let attributes = match mutability {
+            Some(mutability) => quote! { #[solid(rename = #name, #mutability)] },
+            None => quote! { #[solid(rename = #name)] },
+        };
+
+        let outputs = method.outputs();
+        let output = match &method.output {
+            Some(ty) => quote! { #ty },
+            None => quote! { () },
+        };
+        let decode_return = match outputs.len() {
+            0 => quote! { Ok(()) },
+            1 if !matches!(method.output, Some(Type::Tuple(_))) => quote! {
+                <(#output,) as solid::decode::Decode>::decode(buf).map(|(value,)| value)
+            },
+            _ => quote! { <#output as solid::decode::Decode>::decode(buf) },
+        };
+        let doc = format!("Arguments of `{}`", name.value());
+
+        quote! {
+            #[doc = #doc]
+            #[derive(solid::Encode, solid::Decode, solid::Abi)]
+            #attributes
+            pub struct #ident<#(#lifetimes),*> {
+                #(
+                    pub #arg: #arg_ty,
+                )*
+            }
+
+            impl<#(#lifetimes),*> #ident<#(#lifetimes),*> {
+                /// Decodes the values returned by the function
+                pub fn decode_return(buf: &[u8]) -> solid::Result<#output> {
+                    #decode_return
+                }
+            }
+        }
+    });
+
+    let entries = methods.iter().map(|method| {
+        let ident = &method.ident;
+        let lifetimes = method.lifetimes.iter().map(|_| quote! { '_ });
+        let outputs = method.outputs();
+        quote! {
+            {
+                let mut entry = <#ident<#(#lifetimes),*> as solid::abi::Abi>::abi();
+                entry.outputs = vec![#(<#outputs as solid::into_type::IntoType>::param("")),*];
+                entry
+            }
+        }
+    });
+
+    let mut lifetimes = Vec::<&GenericParam>::new();
+    for lifetime in methods.iter().flat_map(|method| &method.lifetimes) {
+        if !lifetimes.iter().any(|existing| existing == &lifetime) {
+            lifetimes.push(lifetime);
+        }
+    }
+
+    let variant = methods.iter().map(|method| &method.ident);
+    let ty = methods.iter().map(|method| {
+        let ident = &method.ident;
+        let lifetimes = &method.lifetimes;
+        quote! { #ident<#(#lifetimes),*> }
+    });
+
+    Ok(quote! {
+        #item
+
+        #[doc = #doc]
+        #vis mod #module {
+            #![allow(unused_imports)]
+            use super::*;
+
+            #(#calls)*
+
+            /// Calls of every function of the interface
+            #[derive(solid::Calls)]
+            pub enum Calls<#(#lifetimes),*> {
+                #(
+                    #variant(#ty),
+                )*
+            }
+
+            /// JSON ABI of the interface
+            pub fn abi() -> solid::abi::Contract {
+                solid::abi::Contract::new(#contract)
+                    #(.entry(#entries))*
+            }
+
+            /// Solidity source of the interface
+            pub fn solidity() -> String {
+                abi().to_solidity()
+            }
+        }
+    })
+}
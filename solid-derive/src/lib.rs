@@ -8,34 +8,78 @@ extern crate quote;
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use proc_macro2::Literal;
 
+use solid_core::calldata::Signature;
 use syn::{
     parse::{
         Parse,
         ParseStream,
     },
     parse_macro_input,
+    punctuated::Punctuated,
+    Attribute,
     DeriveInput,
+    Error,
     Ident,
+    ItemTrait,
+    LitStr,
     Result,
     Token,
+    WherePredicate,
 };
 
+mod abi;
+mod bindings;
+mod bound;
+mod calls;
+mod contract;
 mod decode;
 mod encode;
+mod enums;
+mod field;
+mod interface;
+mod sol;
+mod types;
 
+/// Single `key` or `key = "value"` pair of a `#[solid(...)]` attribute
 #[derive(Debug)]
 pub(crate) struct Solidity {
     ident: Ident,
-    name: Option<Literal>,
+    name: Option<LitStr>,
+}
+
+impl Solidity {
+    /// The value of a `key = "value"` attribute
+    fn value(&self) -> Result<LitStr> {
+        self.name.clone().ok_or_else(|| {
+            Error::new(
+                self.ident.span(),
+                format!(
+                    "`{}` requires a value, e.g. `{} = \"...\"`",
+                    self.ident, self.ident
+                ),
+            )
+        })
+    }
+
+    /// Ensure a `key` attribute was not given a value
+    fn flag(&self) -> Result<()> {
+        match &self.name {
+            Some(name) => Err(Error::new(
+                name.span(),
+                format!("`{}` does not take a value", self.ident),
+            )),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Parse for Solidity {
     fn parse(input: ParseStream) -> Result<Self> {
         let ident: Ident = input.parse()?;
-        let name = if let Ok(_) = input.parse::<Token![=]>() {
-            Some(input.parse::<Literal>()?)
+        let name = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<LitStr>()?)
         } else {
             None
         };
@@ -44,16 +88,256 @@ impl Parse for Solidity {
     }
 }
 
+/// Every key of every `#[solid(...)]` attribute in `attrs`
+pub(crate) fn parse_attributes(attrs: &[Attribute]) -> Result<Vec<Solidity>> {
+    let mut attributes = Vec::new();
+
+    for attr in attrs {
+        if attr.path.is_ident("solid") {
+            attributes
+                .extend(attr.parse_args_with(Punctuated::<Solidity, Token![,]>::parse_terminated)?);
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Attributes placed on the struct itself
+pub(crate) struct StructAttrs {
+    /// `#[solid(rename = "...")]`: function name used in the selector
+    name: LitStr,
+    /// `#[solid(constructor)]`: the call is encoded without a selector
+    constructor: bool,
+    /// `#[solid(bound = "...")]`: where clause predicates replacing the inferred bounds
+    bound: Option<Vec<WherePredicate>>,
+    /// `#[solid(transparent)]`: the struct is encoded exactly like its only field
+    transparent: bool,
+    /// `#[solid(event)]`: the struct describes an event in its JSON ABI
+    event: bool,
+    /// `#[solid(error)]`: the struct describes a custom error in its JSON ABI
+    error: bool,
+    /// `#[solid(anonymous)]`: the event has no signature topic
+    anonymous: bool,
+    /// `#[solid(view)]`, `#[solid(pure)]` or `#[solid(payable)]`: state mutability of the
+    /// function in its JSON ABI
+    mutability: Option<Ident>,
+}
+
+/// Struct keys along with the keys they cannot be combined with
+const CONFLICTS: &[(&str, &[&str])] = &[
+    (
+        "transparent",
+        &[
+            "rename",
+            "constructor",
+            "event",
+            "error",
+            "view",
+            "pure",
+            "payable",
+        ],
+    ),
+    ("constructor", &["event", "error", "view", "pure"]),
+    ("event", &["error", "view", "pure", "payable"]),
+    ("error", &["view", "pure", "payable"]),
+    ("view", &["pure", "payable"]),
+    ("pure", &["payable"]),
+];
+
+impl StructAttrs {
+    fn new(ast: &DeriveInput) -> Result<Self> {
+        let mut attrs = StructAttrs {
+            name: LitStr::new(&ast.ident.to_string(), ast.ident.span()),
+            constructor: false,
+            bound: None,
+            transparent: false,
+            event: false,
+            error: false,
+            anonymous: false,
+            mutability: None,
+        };
+
+        let attributes = parse_attributes(&ast.attrs)?;
+        for attribute in &attributes {
+            match attribute.ident.to_string().as_str() {
+                "constructor" => {
+                    attribute.flag()?;
+                    attrs.constructor = true;
+                }
+
+                "rename" => {
+                    attrs.name = attribute.value()?;
+                }
+
+                "transparent" => {
+                    attribute.flag()?;
+                    attrs.transparent = true;
+                }
+
+                "event" => {
+                    attribute.flag()?;
+                    attrs.event = true;
+                }
+
+                "error" => {
+                    attribute.flag()?;
+                    attrs.error = true;
+                }
+
+                "anonymous" => {
+                    attribute.flag()?;
+                    attrs.anonymous = true;
+                }
+
+                "view" | "pure" | "payable" => {
+                    attribute.flag()?;
+                    attrs.mutability = Some(attribute.ident.clone());
+                }
+
+                "bound" => {
+                    let bound = attribute.value()?;
+                    let predicates = bound
+                        .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)
+                        .map_err(|err| Error::new(bound.span(), err))?;
+                    attrs.bound = Some(predicates.into_iter().collect());
+                }
+
+                _ => {
+                    return Err(Error::new(
+                        attribute.ident.span(),
+                        format!(
+                            "unsupported key `{}` for solid attribute, supported keys are `rename`, `constructor`, `bound`, `transparent`, `event`, `error`, `anonymous`, `view`, `pure` and `payable`",
+                            attribute.ident
+                        ),
+                    ))
+                }
+            }
+        }
+
+        for (key, conflicts) in CONFLICTS {
+            if !attributes.iter().any(|attribute| attribute.ident == key) {
+                continue;
+            }
+
+            if let Some(attribute) = attributes
+                .iter()
+                .find(|attribute| conflicts.iter().any(|other| attribute.ident == other))
+            {
+                return Err(Error::new(
+                    attribute.ident.span(),
+                    format!("`{}` cannot be combined with `{}`", attribute.ident, key),
+                ));
+            }
+        }
+
+        if attrs.anonymous && !attrs.event {
+            let attribute = attributes
+                .iter()
+                .find(|attribute| attribute.ident == "anonymous")
+                .expect("`anonymous` was parsed");
+            return Err(Error::new(
+                attribute.ident.span(),
+                "`anonymous` can only be used on events",
+            ));
+        }
+
+        Ok(attrs)
+    }
+}
+
 #[proc_macro_derive(Encode, attributes(solid))]
 pub fn encode(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
-    TokenStream::from(encode::impl_encode(&ast))
+    TokenStream::from(encode::impl_encode(&ast).unwrap_or_else(|err| err.to_compile_error()))
 }
 
 #[proc_macro_derive(Decode, attributes(solid))]
 pub fn decode(input: TokenStream) -> TokenStream {
-    let mut ast = parse_macro_input!(input as DeriveInput);
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    TokenStream::from(decode::impl_decode(&ast).unwrap_or_else(|err| err.to_compile_error()))
+}
+
+/// Describes a function, constructor, event or error struct as an entry of a JSON ABI
+#[proc_macro_derive(Abi, attributes(solid))]
+pub fn abi(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    TokenStream::from(abi::impl_abi(&ast).unwrap_or_else(|err| err.to_compile_error()))
+}
+
+/// Dispatches calldata to the variants of an enum, each wrapping a `#[derive(Encode, Decode)]`
+/// struct with a `SELECTOR`
+#[proc_macro_derive(Calls, attributes(solid))]
+pub fn calls(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    TokenStream::from(calls::impl_calls(&ast).unwrap_or_else(|err| err.to_compile_error()))
+}
+
+/// Declares a contract interface as a trait
+///
+/// Generates a module named after the trait containing a call struct per method, a `Calls`
+/// enum dispatching calldata to them, the JSON ABI and the Solidity source of the interface.
+#[proc_macro_attribute]
+pub fn interface(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = proc_macro2::TokenStream::from(args);
+    let mut item = parse_macro_input!(input as ItemTrait);
+
+    let tokens = if let Some(arg) = args.into_iter().next() {
+        Err(Error::new(
+            arg.span(),
+            "`interface` does not take any arguments",
+        ))
+    } else {
+        interface::impl_interface(&mut item)
+    };
+
+    TokenStream::from(tokens.unwrap_or_else(|err| err.to_compile_error()))
+}
+
+/// Generates bindings for inline Solidity declarations
+///
+/// Interfaces, contracts and libraries become unit structs whose associated functions encode
+/// calls to their external functions, and structs, enums, user defined value types, events,
+/// errors and constants become the equivalent Rust items, like the bindings written by
+/// `cargo-solid`.
+#[proc_macro]
+pub fn sol(input: TokenStream) -> TokenStream {
+    let source = parse_macro_input!(input as sol::Source);
+
+    TokenStream::from(bindings::expand(&source.items).unwrap_or_else(|err| err.to_compile_error()))
+}
+
+/// Generates bindings for the contracts of a JSON file, relative to the crate's manifest
+///
+/// The file may be a JSON ABI, the output of `solc --combined-json` or `solc --standard-json`,
+/// or a Foundry, Hardhat or Truffle artifact. The bindings are the files `solid-build` and
+/// `cargo-solid` write, each in a module named after its file, and are regenerated whenever the
+/// file changes.
+#[proc_macro]
+pub fn contract(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+
+    TokenStream::from(contract::impl_contract(&path).unwrap_or_else(|err| err.to_compile_error()))
+}
+
+/// Computes the selector of a function signature at compile time
+///
+/// `selector!("transfer(address,uint256)")` expands to a `[u8; 4]` literal. The signature is
+/// validated and hashed in its canonical form, so `uint` is treated as `uint256`.
+#[proc_macro]
+pub fn selector(input: TokenStream) -> TokenStream {
+    let signature = parse_macro_input!(input as LitStr);
+
+    let tokens = match Signature::parse(&signature.value()) {
+        Ok(parsed) => {
+            let selector = parsed.selector();
+            quote! { [#(#selector),*] }
+        }
+        Err(err) => Error::new(signature.span(), err).to_compile_error(),
+    };
 
-    TokenStream::from(decode::impl_decode(&mut ast))
+    TokenStream::from(tokens)
 }
@@ -0,0 +1,551 @@
+use super::bindings::{
+    Constant,
+    Contract,
+    CustomError,
+    Enum,
+    Event,
+    Function,
+    Item,
+    Param,
+    Struct,
+    Ty,
+    Value,
+    ValueType,
+};
+use proc_macro2::{
+    Delimiter,
+    Group,
+    Span,
+    TokenTree,
+};
+use solid_core::value::SolType;
+use syn::{
+    braced,
+    bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    token,
+    Attribute,
+    Error,
+    Ident,
+    Lit,
+    LitInt,
+    LitStr,
+    Result,
+    Token,
+};
+
+/// Solidity source given to `sol!`
+pub(super) struct Source {
+    pub(super) items: Vec<Item>,
+}
+
+impl Parse for Source {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            let docs = docs(input)?;
+            if let Some(item) = item(input, docs, true)? {
+                items.push(item);
+            }
+        }
+
+        Ok(Source { items })
+    }
+}
+
+/// Whether the next token is the identifier `keyword`, which may also be a Rust keyword
+fn peek_keyword(input: ParseStream, keyword: &str) -> bool {
+    matches!(input.cursor().ident(), Some((ident, _)) if ident == keyword)
+}
+
+/// Consumes the identifier `keyword` if it is next
+fn eat_keyword(input: ParseStream, keyword: &str) -> Result<bool> {
+    if peek_keyword(input, keyword) {
+        input.call(Ident::parse_any)?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// NatSpec comments, which are kept as the documentation of the generated items
+fn docs(input: ParseStream) -> Result<Vec<Attribute>> {
+    let mut attributes = input.call(Attribute::parse_outer)?;
+    attributes.retain(|attribute| attribute.path.is_ident("doc"));
+    Ok(attributes)
+}
+
+/// Skips everything up to and including the next `;` or block, for declarations without
+/// bindings such as `pragma`, `import`, `using`, modifiers and state variables
+fn skip_declaration(input: ParseStream) -> Result<()> {
+    loop {
+        if input.is_empty() {
+            return Err(input.error("expected `;`"));
+        }
+        if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+            return Ok(());
+        }
+        match input.parse::<TokenTree>()? {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+/// Ends a function, constructor or modifier with either `;` or a body
+fn skip_body(input: ParseStream) -> Result<()> {
+    if input.peek(token::Brace) {
+        input.parse::<Group>().map(|_| ())
+    } else {
+        input.parse::<Token![;]>().map(|_| ())
+    }
+}
+
+/// Item at the top level of the source, or a member of a contract if `top_level` is false
+fn item(input: ParseStream, docs: Vec<Attribute>, top_level: bool) -> Result<Option<Item>> {
+    let lookahead = input.cursor().ident().map(|(ident, _)| ident.to_string());
+    let keyword = match &lookahead {
+        Some(keyword) => keyword.as_str(),
+        None => {
+            return Err(input.error("expected a Solidity declaration"));
+        }
+    };
+
+    Ok(match keyword {
+        "pragma" | "import" | "using" | "modifier" => {
+            skip_declaration(input)?;
+            None
+        }
+        "abstract" | "interface" | "contract" | "library" if top_level => {
+            Some(Item::Contract(contract(input, docs)?))
+        }
+        "function" => function(input, docs)?.map(Item::Function),
+        "constructor" => {
+            input.call(Ident::parse_any)?;
+            let inputs = params(input, false)?;
+            skip_modifiers(input)?;
+            skip_body(input)?;
+            if top_level {
+                None
+            } else {
+                Some(Item::Constructor(inputs))
+            }
+        }
+        "fallback" | "receive" => {
+            input.call(Ident::parse_any)?;
+            params(input, false)?;
+            skip_modifiers(input)?;
+            if eat_keyword(input, "returns")? {
+                params(input, false)?;
+                skip_modifiers(input)?;
+            }
+            skip_body(input)?;
+            None
+        }
+        "event" => Some(Item::Event(event(input, docs)?)),
+        "error" => {
+            input.call(Ident::parse_any)?;
+            let name = input.call(Ident::parse_any)?;
+            let params = params(input, false)?;
+            input.parse::<Token![;]>()?;
+            Some(Item::Error(CustomError { docs, name, params }))
+        }
+        "struct" => {
+            input.call(Ident::parse_any)?;
+            let name = input.call(Ident::parse_any)?;
+            let content;
+            braced!(content in input);
+            let mut fields = Vec::new();
+            while !content.is_empty() {
+                let ty = ty(&content)?;
+                let name = content.call(Ident::parse_any)?;
+                content.parse::<Token![;]>()?;
+                fields.push(Param {
+                    name: Some(name),
+                    ty,
+                    indexed: false,
+                });
+            }
+            Some(Item::Struct(Struct { docs, name, fields }))
+        }
+        "enum" => {
+            input.call(Ident::parse_any)?;
+            let name = input.call(Ident::parse_any)?;
+            let content;
+            braced!(content in input);
+            let variants = content.parse_terminated::<_, Token![,]>(Ident::parse_any)?;
+            if variants.is_empty() {
+                return Err(Error::new(
+                    name.span(),
+                    "enums require at least one variant",
+                ));
+            }
+            Some(Item::Enum(Enum {
+                docs,
+                name,
+                variants: variants.into_iter().collect(),
+            }))
+        }
+        "type" => {
+            input.call(Ident::parse_any)?;
+            let name = input.call(Ident::parse_any)?;
+            if !eat_keyword(input, "is")? {
+                return Err(input.error("expected `is`"));
+            }
+            let ty = ty(input)?;
+            input.parse::<Token![;]>()?;
+            Some(Item::ValueType(ValueType { docs, name, ty }))
+        }
+        "mapping" => {
+            skip_declaration(input)?;
+            None
+        }
+        _ => variable(input, docs)?.map(Item::Constant),
+    })
+}
+
+fn contract(input: ParseStream, docs: Vec<Attribute>) -> Result<Contract> {
+    eat_keyword(input, "abstract")?;
+    input.call(Ident::parse_any)?;
+    let name = input.call(Ident::parse_any)?;
+
+    let mut bases = Vec::new();
+    if eat_keyword(input, "is")? {
+        loop {
+            let mut base = input.call(Ident::parse_any)?;
+            while input.peek(Token![.]) {
+                input.parse::<Token![.]>()?;
+                base = input.call(Ident::parse_any)?;
+            }
+            // Arguments to the base constructor
+            if input.peek(token::Paren) {
+                input.parse::<Group>()?;
+            }
+            bases.push(base);
+            if !input.peek(Token![,]) {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    let content;
+    braced!(content in input);
+    let mut items = Vec::new();
+    while !content.is_empty() {
+        let docs = self::docs(&content)?;
+        if let Some(item) = item(&content, docs, false)? {
+            items.push(item);
+        }
+    }
+
+    Ok(Contract {
+        docs,
+        name,
+        bases,
+        items,
+    })
+}
+
+/// Skips visibility, mutability and modifier invocations, returning the visibility if given
+fn skip_modifiers(input: ParseStream) -> Result<Option<Ident>> {
+    let mut visibility = None;
+    while !input.is_empty() && !input.peek(Token![;]) && !input.peek(token::Brace) {
+        if peek_keyword(input, "returns") {
+            break;
+        }
+        let ident = input.call(Ident::parse_any)?;
+        match ident.to_string().as_str() {
+            "external" | "public" | "internal" | "private" => visibility = Some(ident),
+            _ => {}
+        }
+        // `override(A, B)` and modifier arguments
+        if input.peek(token::Paren) {
+            input.parse::<Group>()?;
+        }
+    }
+    Ok(visibility)
+}
+
+/// Functions which can be called externally, `None` for `internal` and `private` functions
+fn function(input: ParseStream, docs: Vec<Attribute>) -> Result<Option<Function>> {
+    input.call(Ident::parse_any)?;
+    let name = input.call(Ident::parse_any)?;
+    let inputs = params(input, false)?;
+    let visibility = skip_modifiers(input)?;
+    let outputs = if eat_keyword(input, "returns")? {
+        params(input, false)?
+    } else {
+        Vec::new()
+    };
+    skip_modifiers(input)?;
+    skip_body(input)?;
+
+    Ok(match visibility {
+        Some(visibility) if visibility == "internal" || visibility == "private" => None,
+        _ => Some(Function {
+            docs,
+            name,
+            inputs,
+            outputs,
+        }),
+    })
+}
+
+fn event(input: ParseStream, docs: Vec<Attribute>) -> Result<Event> {
+    input.call(Ident::parse_any)?;
+    let name = input.call(Ident::parse_any)?;
+    let params = params(input, true)?;
+    let anonymous = eat_keyword(input, "anonymous")?;
+    input.parse::<Token![;]>()?;
+
+    Ok(Event {
+        docs,
+        name,
+        params,
+        anonymous,
+    })
+}
+
+/// Parenthesized parameters, with `indexed` allowed in events
+fn params(input: ParseStream, event: bool) -> Result<Vec<Param>> {
+    let content;
+    parenthesized!(content in input);
+
+    let mut params = Vec::new();
+    while !content.is_empty() {
+        let ty = ty(&content)?;
+        let mut indexed = false;
+        let mut name = None;
+
+        while !content.is_empty() && !content.peek(Token![,]) {
+            let ident = content.call(Ident::parse_any)?;
+            match ident.to_string().as_str() {
+                "memory" | "calldata" | "storage" => {}
+                "indexed" if event => indexed = true,
+                _ if name.is_none() => name = Some(ident),
+                _ => return Err(Error::new(ident.span(), "expected `,`")),
+            }
+        }
+
+        params.push(Param { name, ty, indexed });
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(params)
+}
+
+/// Type name, including any array suffixes
+fn ty(input: ParseStream) -> Result<Ty> {
+    let mut name = input.call(Ident::parse_any)?;
+    let mut qualified = false;
+    while input.peek(Token![.]) {
+        input.parse::<Token![.]>()?;
+        name = input.call(Ident::parse_any)?;
+        qualified = true;
+    }
+
+    let span = name.span();
+    let mut ty = match name.to_string().as_str() {
+        _ if qualified => Ty::Named(name),
+        "mapping" => return Err(Error::new(span, "mappings cannot be parameters")),
+        "function" => return Err(Error::new(span, "function types are not supported")),
+        "address" => {
+            eat_keyword(input, "payable")?;
+            Ty::Elementary(SolType::Address, span)
+        }
+        "byte" => Ty::Elementary(SolType::FixedBytes(1), span),
+        elementary => match SolType::parse(elementary) {
+            Ok(SolType::Array(_)) | Ok(SolType::FixedArray(..)) | Ok(SolType::Tuple(_)) => {
+                Ty::Named(name)
+            }
+            Ok(elementary) => Ty::Elementary(elementary, span),
+            Err(_) => Ty::Named(name),
+        },
+    };
+
+    while input.peek(token::Bracket) {
+        let content;
+        let brackets = bracketed!(content in input);
+        let len = if content.is_empty() {
+            None
+        } else {
+            Some(content.parse::<LitInt>()?.base10_parse::<usize>()?)
+        };
+        ty = Ty::Array(Box::new(ty), len, brackets.span);
+    }
+
+    Ok(ty)
+}
+
+/// State variable, which only has bindings if it is a `constant`
+fn variable(input: ParseStream, docs: Vec<Attribute>) -> Result<Option<Constant>> {
+    let ty = ty(input)?;
+
+    let mut constant = false;
+    let mut name = None;
+    while !input.peek(Token![=]) && !input.peek(Token![;]) {
+        let ident = input.call(Ident::parse_any)?;
+        match ident.to_string().as_str() {
+            "constant" => constant = true,
+            "public" | "internal" | "private" | "immutable" | "override" => {}
+            _ => name = Some(ident),
+        }
+    }
+
+    let name = name.ok_or_else(|| input.error("expected the name of the variable"))?;
+    if !constant {
+        skip_declaration(input)?;
+        return Ok(None);
+    }
+
+    input.parse::<Token![=]>()?;
+    let value = value(input)?;
+    input.parse::<Token![;]>()?;
+
+    Ok(Some(Constant {
+        docs,
+        name,
+        ty,
+        value,
+    }))
+}
+
+/// Literal value of a constant
+fn value(input: ParseStream) -> Result<Value> {
+    let negative = input.peek(Token![-]);
+    if negative {
+        input.parse::<Token![-]>()?;
+    }
+
+    if peek_keyword(input, "hex") && input.peek2(LitStr) {
+        let hex = input.call(Ident::parse_any)?;
+        let value = input.parse::<LitStr>()?;
+        let digits = value.value().replace('_', "");
+        let bytes = (0..digits.len())
+            .step_by(2)
+            .map(|index| {
+                digits
+                    .get(index..index + 2)
+                    .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Error::new(value.span(), "invalid hex literal"))?;
+        return Ok(Value::Hex(bytes, hex.span()));
+    }
+
+    let unsupported = |span: Span| {
+        Error::new(
+            span,
+            "unsupported constant value, only literals are supported",
+        )
+    };
+
+    let (text, span) = match input.parse::<Lit>()? {
+        Lit::Bool(value) if !negative => return Ok(Value::Bool(value.value, value.span)),
+        Lit::Str(value) if !negative => return Ok(Value::String(value)),
+        Lit::Int(value) => (value.to_string(), value.span()),
+        Lit::Float(value) => (value.to_string(), value.span()),
+        lit => return Err(unsupported(lit.span())),
+    };
+    let text = text.replace('_', "");
+
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        let mut magnitude = Vec::new();
+        for digit in hex.chars() {
+            let digit = digit.to_digit(16).ok_or_else(|| unsupported(span))?;
+            mul_add(&mut magnitude, 16, digit);
+        }
+        return Ok(Value::Number {
+            negative,
+            magnitude,
+            hex_digits: Some(hex.len()),
+            span,
+        });
+    }
+
+    // Decimal literals may have a fraction and exponent as long as the value is an integer
+    // once the denomination is applied, e.g. `1.5e3 gwei`
+    let (mantissa, exponent) = match text.find(&['e', 'E'][..]) {
+        Some(index) => (
+            &text[..index],
+            text[index + 1..]
+                .parse::<i64>()
+                .map_err(|_| unsupported(span))?,
+        ),
+        None => (text.as_str(), 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+        None => (mantissa, ""),
+    };
+
+    let (power, multiplier) = match input.cursor().ident() {
+        Some((ident, _)) => match ident.to_string().as_str() {
+            "wei" | "seconds" => (0, 1),
+            "gwei" => (9, 1),
+            "ether" => (18, 1),
+            "minutes" => (0, 60),
+            "hours" => (0, 3600),
+            "days" => (0, 86_400),
+            "weeks" => (0, 604_800),
+            _ => return Err(unsupported(ident.span())),
+        },
+        None => (0, 1),
+    };
+    if input.cursor().ident().is_some() {
+        input.call(Ident::parse_any)?;
+    }
+
+    let mut digits = format!("{}{}", integer, fraction);
+    let power = exponent + power - fraction.len() as i64;
+    if power >= 0 {
+        if power > 80 {
+            return Err(unsupported(span));
+        }
+        digits.push_str(&"0".repeat(power as usize));
+    } else {
+        let keep = digits
+            .len()
+            .checked_sub((-power) as usize)
+            .filter(|&keep| digits[keep..].chars().all(|digit| digit == '0'))
+            .ok_or_else(|| Error::new(span, "constant is not an integer"))?;
+        digits.truncate(keep);
+    }
+
+    let mut magnitude = Vec::new();
+    for digit in digits.chars() {
+        let digit = digit.to_digit(10).ok_or_else(|| unsupported(span))?;
+        mul_add(&mut magnitude, 10, digit);
+    }
+    mul_add(&mut magnitude, multiplier, 0);
+
+    Ok(Value::Number {
+        negative,
+        magnitude,
+        hex_digits: None,
+        span,
+    })
+}
+
+/// `value = value * mul + add` on a big endian integer of any size
+fn mul_add(value: &mut Vec<u8>, mul: u32, add: u32) {
+    let mut carry = add as u64;
+    for byte in value.iter_mut().rev() {
+        let product = *byte as u64 * mul as u64 + carry;
+        *byte = product as u8;
+        carry = product >> 8;
+    }
+    while carry > 0 {
+        value.insert(0, carry as u8);
+        carry >>= 8;
+    }
+}
@@ -0,0 +1,94 @@
+use syn::{
+    GenericArgument,
+    PathArguments,
+    Type,
+};
+
+/// Solidity type of a Rust type, if it can be determined from the type's name alone
+///
+/// Only types provided by `solid` (and the primitives and std types it implements `IntoType`
+/// for) are recognised, which is what allows the signature and selector of a derived struct
+/// to be computed while expanding the macro.
+pub(crate) fn resolve(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(reference) => resolve(&reference.elem),
+        Type::Paren(paren) => resolve(&paren.elem),
+        Type::Group(group) => resolve(&group.elem),
+        Type::Slice(slice) => Some(format!("{}[]", resolve(&slice.elem)?)),
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            let elems = tuple
+                .elems
+                .iter()
+                .map(resolve)
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("({})", elems.join(",")))
+        }
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            let ident = segment.ident.to_string();
+
+            match ident.as_str() {
+                "Vec" | "ArrayRef" => {
+                    let inner = match &segment.arguments {
+                        PathArguments::AngleBracketed(arguments) => arguments
+                            .args
+                            .iter()
+                            .filter_map(|argument| match argument {
+                                GenericArgument::Type(ty) => Some(ty),
+                                _ => None,
+                            })
+                            .next()?,
+                        _ => return None,
+                    };
+                    Some(format!("{}[]", resolve(inner)?))
+                }
+                _ => resolve_name(&ident),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn resolve_name(ident: &str) -> Option<String> {
+    let ty = match ident {
+        "bool" => "bool",
+        "str" | "String" => "string",
+        "Bytes" | "BytesBuf" => "bytes",
+        "Address" => "address",
+        "Function" => "function",
+        "BigInt" => "int256",
+        "BigUint" | "U256" => "uint256",
+        "U128" => "uint128",
+        "i8" | "i16" | "i32" | "i64" | "i128" => return Some(format!("int{}", &ident[1..])),
+        "u8" | "u16" | "u32" | "u64" | "u128" => return Some(format!("uint{}", &ident[1..])),
+        _ => return resolve_sized(ident),
+    };
+
+    Some(ty.to_string())
+}
+
+/// `Int<M>`, `Uint<M>` and `Bytes<M>` from `solid::int` and `solid::bytesfix`
+fn resolve_sized(ident: &str) -> Option<String> {
+    let (prefix, size, max) = if let Some(size) = ident.strip_prefix("Uint") {
+        ("uint", size, 256)
+    } else if let Some(size) = ident.strip_prefix("Int") {
+        ("int", size, 256)
+    } else if let Some(size) = ident.strip_prefix("Bytes") {
+        ("bytes", size, 32)
+    } else {
+        return None;
+    };
+
+    let size = size.parse::<usize>().ok()?;
+    let valid = if prefix == "bytes" {
+        size >= 1 && size <= max
+    } else {
+        size >= 8 && size <= max && size % 8 == 0
+    };
+
+    if valid {
+        Some(format!("{}{}", prefix, size))
+    } else {
+        None
+    }
+}
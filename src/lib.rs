@@ -46,6 +46,57 @@
 //! }
 //! ```
 //!
+//! Use `ArrayRef` instead of `Vec` for large arrays to decode elements lazily, without
+//! allocating the whole array up front.
+//!
+//! ```rust
+//! # use solid::{
+//! #     ArrayRef,
+//! #     Builder,
+//! #     Decode,
+//! # };
+//! #
+//! #[derive(Decode)]
+//! struct Balances<'a> {
+//!     block: u64,
+//!     balances: ArrayRef<'a, u128>,
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! # let buf = Builder::new().push(10u64).push(vec![1u128, 2, 3]).build();
+//! let response = <Balances as solid::decode::Decode>::decode(&buf)?;
+//! for balance in response.balances {
+//!     let _balance: u128 = balance?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! `CallData` decodes the arguments of a function call after checking its selector, for
+//! example against a struct deriving both `Encode` and `Decode`.
+//!
+//! ```rust
+//! # use solid::{
+//! #     CallData,
+//! #     Encode,
+//! #     Decode,
+//! # };
+//! #
+//! #[derive(Encode, Decode)]
+//! struct Transfer<'a> {
+//!     to: &'a str,
+//!     amount: u128,
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! # let buf = solid::encode::Encode::encode(&Transfer { to: "daniel", amount: 10 });
+//! let transfer: Transfer = CallData::new(&buf)?.decode("Transfer")?;
+//! # assert_eq!(transfer.to, "daniel");
+//! # assert_eq!(transfer.amount, 10);
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ### Usage with the "deser" feature would look like.
 //!
 //! ```rust
@@ -110,21 +161,566 @@
 //!     creator: String,
 //! }
 //! ```
+//!
+//! When the Solidity type of every field is known at compile time, the derive also generates
+//! the `SIGNATURE` and `SELECTOR` constants and encoding does not hash the signature. This is
+//! the case for the types provided by `solid`, primitives, `String`, `Vec`, slices and tuples
+//! of those, and fields with a `ty` attribute. `selector()` is available either way, hashing
+//! the signature at runtime when there is no `SELECTOR`. `selector!` computes the selector of
+//! any signature at compile time.
+//!
+//! ```rust
+//! # use solid::{
+//! #     Address,
+//! #     Encode,
+//! # };
+//! #
+//! #[derive(Encode)]
+//! #[solid(rename = "transfer")]
+//! struct Transfer {
+//!     to: Address,
+//!     amount: u128,
+//! }
+//!
+//! assert_eq!(Transfer::SIGNATURE, "transfer(address,uint128)");
+//! assert_eq!(Transfer::SELECTOR, solid::selector!("transfer(address,uint128)"));
+//! ```
+//!
+//! Unit structs are calls without any arguments.
+//!
+//! ```rust
+//! # use solid::{
+//! #     Encode,
+//! #     Selector,
+//! # };
+//! #
+//! #[derive(Encode)]
+//! #[solid(rename = "pause")]
+//! struct Pause;
+//!
+//! assert_eq!(solid::encode::Encode::encode(&Pause), Selector::new().build("pause"));
+//! ```
+//!
+//! ### Supported field attributes for `Encode` and `Decode`:
+//!
+//! "ty": Solidity type of the field, used in the function signature in place of the field
+//! type's own. The field is still encoded by its type, so the types must match unless the
+//! field is an integer at least as wide as the Solidity type, e.g. a `u32` holding a `uint24`.
+//! Such values are range checked, failing to decode when they do not fit. `Encode` cannot
+//! fail, so **encoding panics** if such a field holds a value that does not fit, e.g. a `u32`
+//! of `1 << 24` in a `uint24` field; check values before encoding them or convert them with
+//! "with". Field types the macro does not know, such as generic ones, are not checked.
+//!
+//! "skip": The field is neither encoded nor part of the signature, and is set to
+//! `Default::default()` when decoding.
+//!
+//! "with": Path to a module with `encode(&T) -> Vec<u8>` and
+//! `decode(&[u8]) -> solid::Result<T>` functions used instead of the field type's `Encode`
+//! and `Decode` implementations. Combine it with "ty" if `T` does not implement `IntoType`.
+//!
+//! "rename": Name of the field in ABI JSON and EIP-712 type strings. Field names are not
+//! part of the encoding.
+//!
+//! ```rust
+//! # use solid::{
+//! #     Encode,
+//! #     Decode,
+//! #     Selector,
+//! # };
+//! #
+//! mod decimal {
+//!     use solid::{
+//!         decode::Decode,
+//!         encode::Encode,
+//!     };
+//!
+//!     pub fn encode(value: &u64) -> Vec<u8> {
+//!         value.to_string().encode()
+//!     }
+//!
+//!     pub fn decode(buf: &[u8]) -> solid::Result<u64> {
+//!         <&str>::decode(buf)?
+//!             .parse()
+//!             .map_err(|err| solid::Error::Message(format!("{}", err)))
+//!     }
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[solid(rename = "setLimit")]
+//! struct SetLimit {
+//!     #[solid(ty = "uint24")]
+//!     fee: u32,
+//!     #[solid(ty = "string", with = "decimal")]
+//!     limit: u64,
+//!     #[solid(skip)]
+//!     note: Option<String>,
+//!     #[solid(rename = "recipient")]
+//!     to: String,
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! let call = SetLimit {
+//!     fee: 3000,
+//!     limit: 1_000_000,
+//!     note: Some("ignored".to_string()),
+//!     to: "daniel".to_string(),
+//! };
+//!
+//! let buf = solid::encode::Encode::encode(&call);
+//! assert_eq!(buf[..4], Selector::from_signature("setLimit(uint24,string,string)"));
+//!
+//! let decoded = <SetLimit as solid::decode::Decode>::decode(&buf)?;
+//! assert_eq!(decoded.fee, 3000);
+//! assert_eq!(decoded.limit, 1_000_000);
+//! assert_eq!(decoded.note, None);
+//! assert_eq!(decoded.to, "daniel");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Generic and nested structs
+//!
+//! Type parameters of a derived struct are bounded by the traits its fields need, e.g.
+//! `T: Encode + IntoType` for `Encode`. `#[solid(bound = "...")]` replaces the inferred bounds
+//! with the given where clause predicates, in which `'solidity` is the lifetime of the buffer
+//! being decoded. A derived struct nested inside another one is encoded as a tuple: in place
+//! if all of its fields are static, and in the tail otherwise.
+//!
+//! ```rust
+//! # use solid::{
+//! #     CallData,
+//! #     Decode,
+//! #     Encode,
+//! #     SolValue,
+//! # };
+//! #
+//! #[derive(Encode, Decode)]
+//! struct Point {
+//!     x: u64,
+//!     y: u64,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[solid(rename = "page")]
+//! struct Page<T> {
+//!     items: Vec<T>,
+//!     origin: Point,
+//!     next: u64,
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! let page = Page {
+//!     items: vec![1u64, 2],
+//!     origin: Point { x: 3, y: 4 },
+//!     next: 5,
+//! };
+//!
+//! let buf = solid::encode::Encode::encode(&page);
+//! assert_eq!(buf[..4], solid::selector!("page(uint64[],(uint64,uint64),uint64)"));
+//!
+//! let values = CallData::new(&buf)?
+//!     .decode_signature(&"page(uint64[],(uint64,uint64),uint64)".parse()?)?;
+//! match (&values[1], &values[2]) {
+//!     (SolValue::Tuple(origin), SolValue::Uint(next)) => {
+//!         assert_eq!(origin.len(), 2);
+//!         assert_eq!(next[31], 5);
+//!     }
+//!     _ => unreachable!(),
+//! }
+//!
+//! let decoded = <Page<u64> as solid::decode::Decode>::decode(&buf)?;
+//! assert_eq!(decoded.items, vec![1, 2]);
+//! assert_eq!((decoded.origin.x, decoded.origin.y), (3, 4));
+//! assert_eq!(decoded.next, 5);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ```rust
+//! # use solid::Encode;
+//! #
+//! #[derive(Encode)]
+//! #[solid(bound = "T: solid::encode::Encode")]
+//! struct Batch<T> {
+//!     #[solid(ty = "uint256[]")]
+//!     values: Vec<T>,
+//! }
+//! ```
+//!
+//! ### Enums and newtypes
+//!
+//! Enums without fields are Solidity enums, encoded as a `uint8` numbering the variants in
+//! declaration order. Decoding fails for values without a variant. A single field struct with
+//! `#[solid(transparent)]` is encoded and named exactly like its field, which suits
+//! user-defined value types such as `type Price is uint128`.
+//!
+//! ```rust
+//! # use solid::{
+//! #     Builder,
+//! #     Decode,
+//! #     Encode,
+//! #     into_type::IntoType,
+//! # };
+//! #
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! enum Side {
+//!     Buy,
+//!     Sell,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[solid(transparent)]
+//! struct Price(u128);
+//!
+//! #[derive(Encode)]
+//! #[solid(rename = "order")]
+//! struct Order {
+//!     side: Side,
+//!     price: Price,
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! assert_eq!(Side::into_type(), "uint8");
+//! assert_eq!(Price::into_type(), "uint128");
+//!
+//! let buf = solid::encode::Encode::encode(&Order {
+//!     side: Side::Sell,
+//!     price: Price(100),
+//! });
+//! assert_eq!(buf[..4], solid::selector!("order(uint8,uint128)"));
+//!
+//! let (side, price) = <(Side, Price) as solid::decode::Decode>::decode(&buf[4..])?;
+//! assert_eq!((side, price), (Side::Sell, Price(100)));
+//!
+//! let invalid = Builder::new().push(2u8).build();
+//! assert!(<Side as solid::decode::Decode>::decode(&invalid).is_err());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### JSON ABI
+//!
+//! `Abi` describes a struct as an entry of a contract's JSON ABI, using the same attributes as
+//! `Encode`. Structs are functions unless marked `constructor`, `event` or `error`, and
+//! `view`, `pure` and `payable` set the state mutability of functions. Event fields marked
+//! `indexed` are topics and `anonymous` events have no signature topic. Nested derived structs
+//! are listed as tuples with their `components`. `abi::Contract` collects the entries into the
+//! JSON read by `cargo-solid`.
+//!
+//! ```rust
+//! # use solid::{
+//! #     abi::Contract,
+//! #     Abi,
+//! #     Address,
+//! #     Encode,
+//! # };
+//! #
+//! #[derive(Encode)]
+//! struct Point {
+//!     x: u64,
+//!     y: u64,
+//! }
+//!
+//! #[derive(Abi)]
+//! #[solid(rename = "plot", payable)]
+//! struct Plot {
+//!     points: Vec<Point>,
+//! }
+//!
+//! #[derive(Abi)]
+//! #[solid(event)]
+//! struct Transfer {
+//!     #[solid(indexed)]
+//!     from: Address,
+//!     amount: u128,
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! let contract = Contract::new("Canvas").push::<Plot>().push::<Transfer>();
+//! assert_eq!(
+//!     contract.to_json()?,
+//!     concat!(
+//!         r#"[{"type":"function","name":"plot","inputs":[{"name":"points","type":"tuple[]","#,
+//!         r#""internalType":"struct Point[]","components":["#,
+//!         r#"{"name":"x","type":"uint64","internalType":"uint64"},"#,
+//!         r#"{"name":"y","type":"uint64","internalType":"uint64"}]}],"#,
+//!         r#""outputs":[],"stateMutability":"payable"},"#,
+//!         r#"{"type":"event","name":"Transfer","inputs":["#,
+//!         r#"{"name":"from","type":"address","internalType":"address","indexed":true},"#,
+//!         r#"{"name":"amount","type":"uint128","internalType":"uint128","indexed":false}],"#,
+//!         r#""anonymous":false}]"#,
+//!     )
+//! );
+//!
+//! let combined = contract.to_combined_json("Canvas.sol")?;
+//! assert!(combined.starts_with(r#"{"contracts":{"Canvas.sol:Canvas":{"abi":"[{"#));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Dispatching calldata with `Calls`
+//!
+//! `Calls` is derived for enums whose variants each wrap a `#[derive(Encode, Decode)]` struct
+//! with a `SELECTOR`. Encoding forwards to the variant, decoding picks the variant by the
+//! selector in the first 4 bytes, and `SELECTORS` lists the selector of every variant. Two
+//! variants with the same selector fail to compile, as do variants wrapping a call whose
+//! selector is only known at runtime.
+//!
+//! ```rust
+//! # use solid::{
+//! #     Address,
+//! #     Calls,
+//! #     Decode,
+//! #     Encode,
+//! # };
+//! #
+//! #[derive(Encode, Decode)]
+//! #[solid(rename = "transfer")]
+//! struct Transfer {
+//!     to: Address,
+//!     amount: u128,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[solid(rename = "approve")]
+//! struct Approve<'a> {
+//!     spender: Address,
+//!     memo: &'a str,
+//! }
+//!
+//! #[derive(Calls)]
+//! enum Token<'a> {
+//!     Transfer(Transfer),
+//!     Approve(Approve<'a>),
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! assert_eq!(Token::SELECTORS, [Transfer::SELECTOR, Approve::SELECTOR]);
+//!
+//! let buf = solid::encode::Encode::encode(&Token::Approve(Approve {
+//!     spender: Address([0; 32]),
+//!     memo: "allowance",
+//! }));
+//!
+//! match <Token as solid::decode::Decode>::decode(&buf)? {
+//!     Token::Approve(approve) => assert_eq!(approve.memo, "allowance"),
+//!     Token::Transfer(_) => unreachable!(),
+//! }
+//!
+//! let unknown = solid::Selector::new().build("burn");
+//! assert!(<Token as solid::decode::Decode>::decode(&unknown).is_err());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Owned decoding
+//!
+//! Structs without lifetimes decode into values that do not borrow from the buffer, using
+//! `String` for `string`, `BytesBuf` for `bytes` and `Vec` for arrays. They implement
+//! `DecodeOwned`, whether they derive `Decode` or serde's `Deserialize`, so they can be kept
+//! after the buffer is dropped or sent to another thread.
+//!
+//! ```rust
+//! # use serde::Deserialize;
+//! # use solid::{
+//! #     Builder,
+//! #     BytesBuf,
+//! #     Decode,
+//! # };
+//! #
+//! #[derive(Decode, Deserialize)]
+//! struct Response {
+//!     memo: String,
+//!     data: BytesBuf,
+//!     values: Vec<u64>,
+//! }
+//!
+//! fn decode_owned(buf: &[u8]) -> solid::Result<Response> {
+//!     solid::decode_owned(buf)
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! let buf = Builder::new()
+//!     .push("done")
+//!     .push(BytesBuf(vec![1, 2, 3]))
+//!     .push(vec![4u64, 5])
+//!     .build();
+//!
+//! let response = decode_owned(&buf)?;
+//! let deserialized: Response = solid::from_bytes(&buf)?;
+//! drop(buf);
+//!
+//! let response = std::thread::spawn(move || response).join().unwrap();
+//! assert_eq!(response.memo, "done");
+//! assert_eq!(response.data, BytesBuf(vec![1, 2, 3]));
+//! assert_eq!(response.values, vec![4, 5]);
+//! assert_eq!(deserialized.data, response.data);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Interfaces
+//!
+//! `#[solid::interface]` declares a contract as a trait. It generates a module named after the
+//! trait with a call struct per method, deriving `Encode`, `Decode` and `Abi`, whose
+//! `decode_return` decodes the values the function returns. Methods are renamed to
+//! `camelCase` unless given a `rename`, and can be marked `view`, `pure` or `payable`. The
+//! module also holds a `Calls` enum dispatching calldata to the methods, the JSON ABI of the
+//! interface and its Solidity source. As the selectors of `Calls` are constants, the Solidity
+//! type of every argument has to be known at compile time.
+//!
+//! ```rust
+//! # use solid::Address;
+//! #
+//! #[solid::interface]
+//! pub trait IVault {
+//!     #[solid(view)]
+//!     fn balance_of(&self, owner: Address) -> u128;
+//!
+//!     #[solid(payable)]
+//!     fn deposit<'a>(&mut self, memo: &'a str) -> (u64, bool);
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! let call = i_vault::Deposit { memo: "savings" };
+//! let buf = solid::encode::Encode::encode(&call);
+//! assert_eq!(buf[..4], solid::selector!("deposit(string)"));
+//!
+//! match <i_vault::Calls as solid::decode::Decode>::decode(&buf)? {
+//!     i_vault::Calls::Deposit(deposit) => assert_eq!(deposit.memo, "savings"),
+//!     i_vault::Calls::BalanceOf(_) => unreachable!(),
+//! }
+//!
+//! let returned = solid::Builder::new().push(7u64).push(true).build();
+//! assert_eq!(i_vault::Deposit::decode_return(&returned)?, (7, true));
+//!
+//! assert_eq!(
+//!     i_vault::solidity(),
+//!     concat!(
+//!         "interface IVault {\n",
+//!         "    function balanceOf(address owner) external view returns (uint128);\n",
+//!         "    function deposit(string calldata memo) external payable returns (uint64, bool);\n",
+//!         "}",
+//!     )
+//! );
+//! assert_eq!(i_vault::abi().entries().len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Inline Solidity
+//!
+//! `sol!` generates bindings from Solidity declarations copied from a contract, without solc or
+//! a JSON ABI. Each interface, contract or library becomes a unit struct with a function
+//! encoding the calldata of every external function, like the bindings written by
+//! `cargo-solid`. Overloaded functions are suffixed with their parameter types. Structs, enums,
+//! user defined value types, events, errors and constants become Rust items in the calling
+//! scope, along with an `Output` struct for functions whose return values are all named.
+//!
+//! ```rust
+//! # use solid::{
+//! #     decode::Decode,
+//! #     encode::Encode,
+//! #     int::Uint256,
+//! #     Address,
+//! # };
+//! #
+//! solid::sol! {
+//!     uint8 constant DECIMALS = 18;
+//!
+//!     interface IERC20 {
+//!         event Transfer(address indexed from, address indexed to, uint256 value);
+//!         error InsufficientBalance(uint256 available, uint256 required);
+//!
+//!         function transfer(address to, uint256 amount) external returns (bool);
+//!         function balanceOf(address owner) external view returns (uint256 balance);
+//!     }
+//! }
+//!
+//! # fn main() -> solid::Result<()> {
+//! let buf = IERC20::transfer(Address([0; 32]), Uint256([0; 32]));
+//! assert_eq!(buf[..4], solid::selector!("transfer(address,uint256)"));
+//! assert_eq!(DECIMALS, 18);
+//!
+//! let returned = solid::Builder::new().push(Uint256([1; 32])).build();
+//! let output = BalanceOfOutput::decode(&returned)?;
+//! assert_eq!(output.balance.0, [1; 32]);
+//!
+//! let revert = InsufficientBalance {
+//!     available: Uint256([0; 32]),
+//!     required: Uint256([2; 32]),
+//! }
+//! .encode();
+//! assert_eq!(revert[..4], InsufficientBalance::SELECTOR);
+//! assert_eq!(Transfer::SIGNATURE, "Transfer(address,address,uint256)");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Build artifacts
+//!
+//! `contract!` generates the bindings `cargo-solid` and `solid-build` write from a JSON file
+//! read at compile time, relative to the crate's `Cargo.toml`. The file can be a plain JSON
+//! ABI, the output of `solc --combined-json abi` or `solc --standard-json`, or a Foundry,
+//! Hardhat or Truffle artifact. Each contract is placed in a module named after the file
+//! `cargo-solid` would write it to, and the crate is rebuilt whenever the file changes.
+//!
+//! ```rust
+//! # use solid::decode::Decode;
+//! #
+//! solid::contract!("examples/cargo-solid-example/stateful.json");
+//!
+//! # fn main() -> solid::Result<()> {
+//! let buf = stateful_contract::StatefulContract::set_message("hello");
+//! assert_eq!(buf[..4], solid::selector!("setMessage(string)"));
+//!
+//! let returned = solid::Builder::new()
+//!     .push("hello")
+//!     .push(solid::Bytes(&[1, 2]))
+//!     .push(solid::bytesfix::Bytes10([0; 10]))
+//!     .build();
+//! let output = stateful_contract::GetDetailsNamedOutput::decode(&returned)?;
+//! assert_eq!(output.message_, "hello");
+//! # Ok(())
+//! # }
+//! ```
 #[cfg(feature = "derive")]
 pub use solid_derive as derive;
 
 #[cfg(feature = "derive")]
 pub use solid_derive::{
+    contract,
+    interface,
+    selector,
+    sol,
+    Abi,
+    Calls,
     Decode,
     Encode,
 };
 
 pub use solid_core::{
+    abi,
     address::Address,
+    array::{
+        ArrayRef,
+        DecodeIter,
+    },
     builder::Builder,
-    bytes::Bytes,
+    bytes::{
+        Bytes,
+        BytesBuf,
+    },
     bytesfix,
-    decode,
+    calldata::{
+        CallData,
+        Signature,
+    },
+    decode::{
+        self,
+        decode_owned,
+        DecodeOwned,
+    },
     encode,
     error::{
         Error,
@@ -133,9 +729,28 @@ pub use solid_core::{
     function::Function,
     int,
     into_type,
+    reader::{
+        from_reader,
+        Reader,
+    },
+    registry::{
+        Call,
+        Registry,
+    },
     selector::Selector,
+    value::{
+        SolType,
+        SolValue,
+    },
+    view::{
+        AbiView,
+        ArrayView,
+    },
 };
 
+#[cfg(feature = "async")]
+pub use solid_core::reader::from_async_reader;
+
 #[cfg(feature = "deser")]
 pub use solid_core::derive::{
     from_bytes,
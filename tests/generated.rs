@@ -0,0 +1,121 @@
+//! Compiles the bindings `solid-build` generates for its fixtures and decodes known logs and
+//! reverts with them
+
+mod vault {
+    solid::contract!("solid-build/fixtures/vault.json");
+}
+
+mod standard {
+    solid::contract!("solid-build/fixtures/standard.json");
+}
+
+mod two_contracts {
+    solid::contract!("solid-build/fixtures/two_contracts.json");
+}
+
+mod overloads {
+    solid::contract!("solid-build/fixtures/overloads.json");
+}
+
+mod foundry {
+    solid::contract!("solid-build/fixtures/artifacts/foundry/Token.sol/Token.json");
+}
+
+mod hardhat {
+    solid::contract!("solid-build/fixtures/artifacts/hardhat/contracts/Counter.sol/Counter.json");
+}
+
+mod truffle {
+    solid::contract!("solid-build/fixtures/artifacts/truffle/OwnableUpgradeable.json");
+}
+
+use vault::vault::{
+    Insufficient,
+    Opened,
+    Rejected,
+    VaultErrors,
+    VaultEvents,
+};
+
+fn decode_hex(words: &[&str]) -> Vec<u8> {
+    hex::decode(words.concat()).unwrap()
+}
+
+#[test]
+fn decode_log_test() -> solid::Result<()> {
+    let mut id = [0u8; 32];
+    id[31] = 7;
+    let topics = [Opened::TOPIC, id];
+
+    // `Opened(7, ("savings", [1, 2], (30, "low")))`
+    let data = decode_hex(&[
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "0000000000000000000000000000000000000000000000000000000000000060",
+        "00000000000000000000000000000000000000000000000000000000000000a0",
+        "0000000000000000000000000000000000000000000000000000000000000100",
+        "0000000000000000000000000000000000000000000000000000000000000007",
+        "736176696e677300000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "000000000000000000000000000000000000000000000000000000000000001e",
+        "0000000000000000000000000000000000000000000000000000000000000040",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "6c6f770000000000000000000000000000000000000000000000000000000000",
+    ]);
+
+    let opened = match VaultEvents::decode_log(&topics, &data)? {
+        VaultEvents::Opened(opened) => opened,
+        VaultEvents::Labeled(_) => panic!("decoded the wrong event"),
+    };
+
+    assert_eq!(opened.id.0[31], 7);
+    assert_eq!(opened.position.memo, "savings");
+    assert_eq!(opened.position.amounts.len(), 2);
+    assert_eq!(opened.position.amounts[1].0[31], 2);
+    assert_eq!(opened.position.fee.rate, 30);
+    assert_eq!(opened.position.fee.note, "low");
+
+    Ok(())
+}
+
+#[test]
+fn decode_revert_test() -> solid::Result<()> {
+    // `Rejected("paused")`
+    let revert = decode_hex(&[
+        "60fcb063",
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "0000000000000000000000000000000000000000000000000000000000000006",
+        "7061757365640000000000000000000000000000000000000000000000000000",
+    ]);
+
+    match VaultErrors::decode_revert(&revert)? {
+        VaultErrors::Rejected(rejected) => assert_eq!(rejected.reason, "paused"),
+        _ => panic!("decoded the wrong error"),
+    }
+
+    // `Insufficient(1, 2)`
+    let revert = decode_hex(&[
+        "e8620800",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+    ]);
+
+    let insufficient = Insufficient::decode_revert(&revert)?;
+    assert_eq!(insufficient.available.0[31], 1);
+    assert_eq!(insufficient.required.0[31], 2);
+
+    assert!(Rejected::decode_revert(&revert).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn encode_call_test() {
+    let buf =
+        standard::token::Token::transfer(solid::Address([0; 32]), solid::int::Uint256([0; 32]));
+    assert_eq!(buf[..4], solid::selector!("transfer(address,uint256)"));
+
+    let buf = two_contracts::token_factory::TokenFactory::create("SLD");
+    assert_eq!(buf[..4], solid::selector!("create(string)"));
+}
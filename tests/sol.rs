@@ -0,0 +1,42 @@
+use solid::{
+    decode::Decode as _,
+    int::Uint256,
+    Address,
+    Builder,
+};
+
+solid::sol! {
+    interface IPair {
+        struct Reserves {
+            uint112[2] amounts;
+            uint32 timestamp;
+        }
+
+        function swap(uint256[2] calldata amounts, address[] calldata path) external;
+        function reserves() external view returns (Reserves memory);
+    }
+}
+
+#[test]
+fn fixed_array_params() {
+    let buf = IPair::swap([Uint256([1; 32]), Uint256([2; 32])], vec![Address([3; 32])]);
+
+    assert_eq!(buf[..4], solid::selector!("swap(uint256[2],address[])"));
+
+    // The fixed-size array is stored in place, ahead of the offset of the dynamic one
+    assert_eq!(buf[4..36], [1; 32]);
+    assert_eq!(buf[36..68], [2; 32]);
+    assert_eq!(buf[99], 96);
+}
+
+#[test]
+fn fixed_array_fields() -> solid::Result<()> {
+    let buf = Builder::new().push([5u128, 6u128]).push(7u32).build();
+    let reserves = Reserves::decode(&buf)?;
+
+    assert_eq!(reserves.amounts[0].0[13], 5);
+    assert_eq!(reserves.amounts[1].0[13], 6);
+    assert_eq!(reserves.timestamp, 7);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use solid::{
+    decode::Decode as _,
+    encode::Encode as _,
+    CallData,
+    Decode,
+    Encode,
+    SolValue,
+};
+
+#[derive(Encode, Decode)]
+#[solid(rename = "f")]
+struct Call {
+    pair: (u64, u64),
+    x: u64,
+}
+
+fn word(value: u8) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[31] = value;
+    word
+}
+
+#[test]
+fn static_tuple_field() -> solid::Result<()> {
+    let call = Call {
+        pair: (64, 3),
+        x: 1,
+    };
+    let buf = call.encode();
+
+    // `f((uint64,uint64),uint64)` with the tuple stored in place rather than behind an offset
+    let expected = [word(64), word(3), word(1)].concat();
+    assert_eq!(buf[..4], solid::selector!("f((uint64,uint64),uint64)"));
+    assert_eq!(buf[4..], expected[..]);
+    assert_eq!(buf.len(), 4 + 32 * 3);
+
+    let values = CallData::new(&buf)?.decode_signature(&"f((uint64,uint64),uint64)".parse()?)?;
+    match (&values[0], &values[1]) {
+        (SolValue::Tuple(pair), SolValue::Uint(x)) => {
+            assert_eq!(pair.len(), 2);
+            assert_eq!(x[31], 1);
+        }
+        _ => unreachable!(),
+    }
+
+    let decoded = Call::decode(&buf)?;
+    assert_eq!(decoded.pair, (64, 3));
+    assert_eq!(decoded.x, 1);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use solid::{
+    decode::Decode as _,
+    encode::Encode as _,
+    Decode,
+    Encode,
+};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[solid(rename = "setFee")]
+struct SetFee {
+    #[solid(ty = "uint24")]
+    fee: u32,
+    #[solid(ty = "int8")]
+    tick: i64,
+}
+
+fn word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+#[test]
+fn narrowed_fields_test() -> solid::Result<()> {
+    let call = SetFee {
+        fee: 3000,
+        tick: -1,
+    };
+
+    let buf = call.encode();
+    assert_eq!(buf[..4], solid::selector!("setFee(uint24,int8)"));
+    assert_eq!(buf[4..36], word(3000));
+    assert_eq!(buf[36..], [0xff; 32]);
+    assert_eq!(SetFee::decode(&buf)?, call);
+
+    // Values that do not fit into the Solidity types are rejected when decoding
+    let mut invalid = buf.clone();
+    invalid[4 + 28] = 1;
+    assert!(SetFee::decode(&invalid).is_err());
+
+    let mut invalid = buf;
+    invalid[36 + 31] = 0x7f;
+    assert!(SetFee::decode(&invalid).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "cannot encode field `fee`")]
+fn narrowed_encode_test() {
+    SetFee {
+        fee: 1 << 24,
+        tick: 0,
+    }
+    .encode();
+}
+
+#[derive(Debug, PartialEq, Decode)]
+struct Generic<T> {
+    #[solid(ty = "string")]
+    value: T,
+    amount: u64,
+}
+
+#[test]
+fn generic_ty_field_test() -> solid::Result<()> {
+    let buf = solid::Builder::new()
+        .push("random string")
+        .push(7u64)
+        .build();
+
+    let decoded = Generic::<String>::decode(&buf)?;
+    assert_eq!(decoded.value, "random string");
+    assert_eq!(decoded.amount, 7);
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use solid::{
+    Abi,
+    Address,
+};
+
+#[derive(Abi)]
+#[solid(rename = "transfer")]
+struct Transfer {
+    #[solid(indexed)]
+    to: Address,
+    amount: u128,
+}
+
+fn main() {}
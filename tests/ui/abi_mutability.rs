@@ -0,0 +1,9 @@
+use solid::Abi;
+
+#[derive(Abi)]
+#[solid(view, payable)]
+struct Deposit {
+    amount: u128,
+}
+
+fn main() {}
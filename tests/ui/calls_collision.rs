@@ -0,0 +1,25 @@
+use solid::{
+    Calls,
+    Decode,
+    Encode,
+};
+
+#[derive(Encode, Decode)]
+#[solid(rename = "transfer")]
+struct Transfer {
+    amount: u128,
+}
+
+#[derive(Encode, Decode)]
+#[solid(rename = "transfer")]
+struct Send {
+    value: u128,
+}
+
+#[derive(Calls)]
+enum Token {
+    Transfer(Transfer),
+    Send(Send),
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use solid::{
+    Calls,
+    Decode,
+    Encode,
+};
+
+#[derive(Encode, Decode)]
+struct Order {
+    id: u64,
+}
+
+#[derive(Encode, Decode)]
+#[solid(rename = "fill")]
+struct Fill {
+    order: Order,
+}
+
+#[derive(Calls)]
+enum Exchange {
+    Fill(Fill),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use solid::Calls;
+
+#[derive(Calls)]
+struct Token {
+    amount: u128,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use solid::{
+    Calls,
+    Decode,
+    Encode,
+};
+
+#[derive(Encode, Decode)]
+#[solid(rename = "transfer")]
+struct Transfer {
+    amount: u128,
+}
+
+#[derive(Calls)]
+enum Token {
+    Transfer(Transfer),
+    Burn { amount: u128 },
+}
+
+fn main() {}
@@ -0,0 +1,3 @@
+solid::contract!("tests/ui/missing.json");
+
+fn main() {}
@@ -0,0 +1,8 @@
+use solid::Decode;
+
+#[derive(Decode)]
+enum Transfer {
+    To(String),
+}
+
+fn main() {}
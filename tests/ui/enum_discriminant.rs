@@ -0,0 +1,9 @@
+use solid::Encode;
+
+#[derive(Encode)]
+enum Side {
+    Buy = 1,
+    Sell,
+}
+
+fn main() {}
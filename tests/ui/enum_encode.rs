@@ -0,0 +1,8 @@
+use solid::Encode;
+
+#[derive(Encode)]
+enum Transfer {
+    To(String),
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[solid::interface]
+pub trait Token {
+    fn transfer<T>(&mut self, amount: T);
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[solid::interface]
+pub trait Token {
+    fn transfer(&mut self, memo: &str);
+}
+
+fn main() {}
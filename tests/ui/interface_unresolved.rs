@@ -0,0 +1,16 @@
+use solid::{
+    Decode,
+    Encode,
+};
+
+#[derive(Encode, Decode)]
+pub struct Order {
+    id: u64,
+}
+
+#[solid::interface]
+pub trait Exchange {
+    fn fill(&mut self, order: Order, amount: u64);
+}
+
+fn main() {}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = solid::selector!("transfer(address,uint7)");
+}
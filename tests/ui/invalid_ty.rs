@@ -0,0 +1,9 @@
+use solid::Encode;
+
+#[derive(Encode)]
+struct Transfer {
+    #[solid(ty = "uint7")]
+    amount: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use solid::Encode;
+
+#[derive(Encode)]
+struct Transfer {
+    #[solid(with = "not a path")]
+    amount: u64,
+}
+
+fn main() {}
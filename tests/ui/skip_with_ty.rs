@@ -0,0 +1,9 @@
+use solid::Decode;
+
+#[derive(Decode)]
+struct Transfer {
+    #[solid(skip, ty = "uint24")]
+    amount: u32,
+}
+
+fn main() {}
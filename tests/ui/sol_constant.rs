@@ -0,0 +1,5 @@
+solid::sol! {
+    uint8 constant MAX = 256;
+}
+
+fn main() {}
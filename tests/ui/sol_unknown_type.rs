@@ -0,0 +1,7 @@
+solid::sol! {
+    interface IVault {
+        function open(Position calldata position) external;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use solid::Encode;
+
+#[derive(Encode)]
+#[solid(transparent)]
+struct Price {
+    amount: u128,
+    decimals: u8,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use solid::Encode;
+
+#[derive(Encode)]
+#[solid(transparent, rename = "price")]
+struct Price(u128);
+
+fn main() {}
@@ -0,0 +1,15 @@
+use solid::Encode;
+
+#[derive(Encode)]
+struct Transfer {
+    #[solid(ty = "string")]
+    amount: u64,
+}
+
+#[derive(Encode)]
+struct Approve {
+    #[solid(ty = "uint128")]
+    amount: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use solid::Encode;
+
+#[derive(Encode)]
+#[solid(constructor = "true")]
+struct Contract {
+    owner: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use solid::Encode;
+
+#[derive(Encode)]
+union Transfer {
+    amount: u64,
+}
+
+fn main() {}
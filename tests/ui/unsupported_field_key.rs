@@ -0,0 +1,9 @@
+use solid::Encode;
+
+#[derive(Encode)]
+struct Transfer {
+    #[solid(optional)]
+    amount: u64,
+}
+
+fn main() {}
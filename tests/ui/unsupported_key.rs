@@ -0,0 +1,9 @@
+use solid::Encode;
+
+#[derive(Encode)]
+#[solid(internal)]
+struct Transfer {
+    amount: u64,
+}
+
+fn main() {}